@@ -0,0 +1,53 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+use toml::Table;
+
+// Generates one test per answer in the `dNN/input/answers.toml` files.
+fn main() {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("..");
+    let mut tests = String::new();
+
+    for day in 1..=25 {
+        let input_dir = root.join(format!("d{day:02}")).join("input");
+        let answers_path = input_dir.join("answers.toml");
+        println!("cargo:rerun-if-changed={}", input_dir.display());
+        println!("cargo:rerun-if-changed={}", answers_path.display());
+
+        let Ok(content) = fs::read_to_string(&answers_path) else {
+            continue;
+        };
+        let table: Table = content
+            .parse()
+            .unwrap_or_else(|error| panic!("{}: {error}", answers_path.display()));
+
+        for (input, parts) in table.iter() {
+            let Some(parts) = parts.as_table() else {
+                continue;
+            };
+            let name: String = input
+                .trim_end_matches(".txt")
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect();
+
+            for key in parts.keys() {
+                let part = match key.as_str() {
+                    "part1" => "One",
+                    "part2" => "Two",
+                    _ => continue,
+                };
+                writeln!(
+                    tests,
+                    "#[test]\nfn d{day:02}_{name}_{key}() {{\n    check_answer({day}, {input:?}, Part::{part});\n}}\n"
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("answer_tests.rs"), tests).unwrap();
+}
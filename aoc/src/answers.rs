@@ -0,0 +1,252 @@
+use std::fs;
+use std::path::PathBuf;
+
+use toml::{Table, Value};
+use utils::params::{apply_params, Param};
+use utils::solution::{Answer, Part};
+
+use crate::days::Day;
+use crate::input::InputSource;
+use crate::runner::{catch_panic_with_location, Failure};
+
+/// Lives next to the inputs of a day, one table per input file. `params`
+/// overrides the day's parameters for that input:
+///
+/// ```toml
+/// ["input_test.txt"]
+/// params = { dancers = 5 }
+/// part1 = 31
+/// part2 = "abc"
+/// ```
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub input: String,
+    pub part: Part,
+    pub answer: Answer,
+    pub params: Vec<Param>,
+}
+
+pub fn answers_path(day: &Day) -> PathBuf {
+    day.input_dir().join(ANSWERS_FILE)
+}
+
+pub fn parse_answers(content: &str) -> Result<Vec<Expected>, String> {
+    let table: Table = content.parse().map_err(|error| format!("{error}"))?;
+    let mut rtn = Vec::new();
+
+    for (input, parts) in table.iter() {
+        let parts = parts
+            .as_table()
+            .ok_or_else(|| format!("{input:?} is not a table of parts"))?;
+        let params = match parts.get("params") {
+            Some(params) => parse_params(input, params)?,
+            None => Vec::new(),
+        };
+
+        for (key, value) in parts.iter().filter(|(key, _)| *key != "params") {
+            let part = key
+                .strip_prefix("part")
+                .and_then(|number| number.parse::<u8>().ok())
+                .and_then(|number| Part::try_from(number).ok())
+                .ok_or_else(|| format!("{input:?} has an unknown key {key:?}"))?;
+            let answer = match value {
+                Value::Integer(value) => Answer::Number(*value),
+                Value::String(value) => Answer::Text(value.clone()),
+                _ => return Err(format!("{input:?} {key} is neither a number nor a string")),
+            };
+
+            rtn.push(Expected {
+                input: input.clone(),
+                part,
+                answer,
+                params: params.clone(),
+            });
+        }
+    }
+
+    Ok(rtn)
+}
+
+fn parse_params(input: &str, params: &Value) -> Result<Vec<Param>, String> {
+    let params = params
+        .as_table()
+        .ok_or_else(|| format!("{input:?} params is not a table"))?;
+
+    params
+        .iter()
+        .map(|(name, value)| {
+            let value = match value {
+                Value::String(value) => value.clone(),
+                Value::Integer(_) | Value::Float(_) | Value::Boolean(_) => value.to_string(),
+                _ => return Err(format!("{input:?} parameter {name} is not a single value")),
+            };
+            Ok(Param::new(name, value))
+        })
+        .collect()
+}
+
+/// The expected answers of `day`, empty when the day has no answers file.
+pub fn load_answers(day: &Day) -> Result<Vec<Expected>, String> {
+    let path = answers_path(day);
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|error| format!("Could not read {}: {error}", path.display()))?;
+    parse_answers(&content).map_err(|error| format!("{}: {error}", path.display()))
+}
+
+#[derive(Debug)]
+pub struct Verification {
+    pub day: u8,
+    pub expected: Expected,
+    pub actual: Result<Answer, Failure>,
+}
+
+impl Verification {
+    pub fn is_success(&self) -> bool {
+        matches!(&self.actual, Ok(answer) if *answer == self.expected.answer)
+    }
+}
+
+/// The parameters the answers file of `day` lists for the input `name`.
+pub fn input_params(day: &Day, name: &str) -> Result<Vec<Param>, String> {
+    Ok(load_answers(day)?
+        .into_iter()
+        .find(|e| e.input == name)
+        .map(|e| e.params)
+        .unwrap_or_default())
+}
+
+/// Solves every input listed in the answers file of `day` and compares the
+/// results. Each input is parsed once for all of its parts, with the
+/// parameters listed for it.
+pub fn verify_day(day: &Day) -> Result<Vec<Verification>, String> {
+    let answers = load_answers(day)?;
+    let mut rtn = Vec::new();
+
+    let mut inputs: Vec<&str> = answers.iter().map(|e| e.input.as_str()).collect();
+    inputs.dedup();
+
+    for input in inputs {
+        let expected: Vec<&Expected> = answers.iter().filter(|e| e.input == input).collect();
+
+        let solved = InputSource::resolve(day, input)
+            .and_then(|source| source.read())
+            .map_err(Failure::Input)
+            .and_then(|content| {
+                let mut solution =
+                    catch_panic_with_location(|| (day.parse)(&content))?.map_err(Failure::Parse)?;
+                apply_params(&mut *solution, &expected[0].params).map_err(Failure::Param)?;
+
+                catch_panic_with_location(|| {
+                    expected
+                        .iter()
+                        .map(|e| solution.solve(e.part))
+                        .collect::<Vec<_>>()
+                })
+            });
+
+        match solved {
+            Ok(actual) => {
+                for (expected, actual) in expected.into_iter().zip(actual) {
+                    rtn.push(Verification {
+                        day: day.number,
+                        expected: expected.clone(),
+                        actual: Ok(actual),
+                    });
+                }
+            }
+            Err(failure) => {
+                for expected in expected {
+                    rtn.push(Verification {
+                        day: day.number,
+                        expected: expected.clone(),
+                        actual: Err(failure.clone()),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(rtn)
+}
+
+#[cfg(test)]
+mod answers_test {
+    use utils::params::{apply_params, Param};
+    use utils::solution::{Answer, Part};
+
+    use crate::answers::{load_answers, parse_answers, Expected};
+    use crate::days::get_day;
+    use crate::input::InputSource;
+
+    #[test]
+    fn test_parse_answers() {
+        let expected =
+            parse_answers("[\"input_test.txt\"]\npart1 = 31\npart2 = \"abc\"\n").unwrap();
+        assert_eq!(
+            expected,
+            vec![
+                Expected {
+                    input: "input_test.txt".to_string(),
+                    part: Part::One,
+                    answer: Answer::Number(31),
+                    params: Vec::new(),
+                },
+                Expected {
+                    input: "input_test.txt".to_string(),
+                    part: Part::Two,
+                    answer: Answer::Text("abc".to_string()),
+                    params: Vec::new(),
+                },
+            ]
+        );
+
+        assert!(parse_answers("[\"input_test.txt\"]\npart3 = 1\n").is_err());
+        assert!(parse_answers("[\"input_test.txt\"]\npart1 = 1.5\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_answers_with_params() {
+        let expected = parse_answers(
+            "[\"input_test.txt\"]\nparams = { dancers = 5, name = \"abc\" }\npart1 = 31\n",
+        )
+        .unwrap();
+        assert_eq!(expected.len(), 1);
+        assert_eq!(
+            expected[0].params,
+            vec![Param::new("dancers", "5"), Param::new("name", "abc")]
+        );
+
+        assert!(parse_answers("[\"input_test.txt\"]\nparams = 5\npart1 = 1\n").is_err());
+        assert!(
+            parse_answers("[\"input_test.txt\"]\nparams = { size = [1] }\npart1 = 1\n").is_err()
+        );
+    }
+
+    fn check_answer(day: u8, input: &str, part: Part) {
+        let day = get_day(day).unwrap();
+        let expected = load_answers(day)
+            .unwrap()
+            .into_iter()
+            .find(|e| e.input == input && e.part == part)
+            .unwrap();
+
+        let content = InputSource::resolve(day, input).unwrap().read().unwrap();
+        let mut solution = (day.parse)(&content).unwrap();
+        apply_params(&mut *solution, &expected.params).unwrap();
+        let actual = solution.solve(part);
+
+        assert_eq!(
+            actual, expected.answer,
+            "day {} {input} part {part}",
+            day.number
+        );
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/answer_tests.rs"));
+}
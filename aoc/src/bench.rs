@@ -0,0 +1,331 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::Instant;
+
+use serde_json::{json, Map, Value};
+use utils::params::Param;
+use utils::solution::Part;
+
+use crate::days::Day;
+use crate::input::InputSource;
+use crate::runner::{catch_panic_with_location, set_params, Failure};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl Phase {
+    pub fn get_phases() -> [Phase; 3] {
+        [
+            Phase::Parse,
+            Phase::Solve(Part::One),
+            Phase::Solve(Part::Two),
+        ]
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Solve(Part::One) => "part1",
+            Phase::Solve(Part::Two) => "part2",
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.key())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 10,
+        }
+    }
+}
+
+/// Summary of the measured samples, all values in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub p95: f64,
+    pub std_dev: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[f64]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|l, r| l.total_cmp(r));
+
+        let len = sorted.len();
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2.0
+        } else {
+            sorted[len / 2]
+        };
+        let mean = sorted.iter().sum::<f64>() / len as f64;
+        // Nearest rank
+        let p95 = sorted[((len as f64 * 0.95).ceil() as usize).max(1) - 1];
+        let std_dev = if len > 1 {
+            let variance =
+                sorted.iter().map(|i| (i - mean).powi(2)).sum::<f64>() / (len - 1) as f64;
+            variance.sqrt()
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            samples: len,
+            min: sorted[0],
+            median,
+            mean,
+            p95,
+            std_dev,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct DayBench {
+    pub day: u8,
+    pub phases: Vec<(Phase, Stats)>,
+}
+
+fn measure(config: &BenchConfig, mut f: impl FnMut()) -> Stats {
+    for _ in 0..config.warmup {
+        f();
+    }
+
+    let samples: Vec<f64> = (0..config.iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed().as_nanos() as f64
+        })
+        .collect();
+
+    Stats::from_samples(&samples).unwrap()
+}
+
+/// Times parsing and both parts of `day` separately. The parts are solved on
+/// a single parsed input.
+pub fn bench_day(
+    day: &Day,
+    input: &str,
+    params: &[Param],
+    config: &BenchConfig,
+) -> Result<DayBench, Failure> {
+    let (name, input) = InputSource::resolve(day, input)
+        .and_then(|source| Ok((source.name(), source.read()?)))
+        .map_err(Failure::Input)?;
+
+    let mut solution =
+        catch_panic_with_location(|| (day.parse)(&input))?.map_err(Failure::Parse)?;
+    set_params(day, &name, &mut *solution, params)?;
+
+    catch_panic_with_location(|| {
+        let mut phases = Vec::new();
+
+        phases.push((
+            Phase::Parse,
+            measure(config, || {
+                let _ = black_box((day.parse)(black_box(&input)));
+            }),
+        ));
+
+        for part in Part::get_parts() {
+            phases.push((
+                Phase::Solve(part),
+                measure(config, || {
+                    black_box(solution.solve(part));
+                }),
+            ));
+        }
+
+        DayBench {
+            day: day.number,
+            phases,
+        }
+    })
+}
+
+pub fn format_ns(ns: f64) -> String {
+    if ns < 1_000.0 {
+        format!("{ns:.0} ns")
+    } else if ns < 1_000_000.0 {
+        format!("{:.2} µs", ns / 1_000.0)
+    } else if ns < 1_000_000_000.0 {
+        format!("{:.2} ms", ns / 1_000_000.0)
+    } else {
+        format!("{:.2} s", ns / 1_000_000_000.0)
+    }
+}
+
+/// Median per day and phase, the numbers a later run is compared against.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Baseline {
+    pub medians: BTreeMap<(u8, Phase), f64>,
+}
+
+impl Baseline {
+    pub fn from_benches(benches: &[DayBench]) -> Self {
+        let mut medians = BTreeMap::new();
+        for bench in benches {
+            for (phase, stats) in bench.phases.iter() {
+                medians.insert((bench.day, *phase), stats.median);
+            }
+        }
+        Self { medians }
+    }
+
+    pub fn to_json(&self) -> Value {
+        let mut days: Map<String, Value> = Map::new();
+        for ((day, phase), median) in self.medians.iter() {
+            let entry = days.entry(day.to_string()).or_insert_with(|| json!({}));
+            entry[phase.key()] = json!({ "median_ns": median });
+        }
+        json!({ "days": days })
+    }
+
+    pub fn from_json(value: &Value) -> Result<Self, String> {
+        let mut medians = BTreeMap::new();
+        let days = value["days"]
+            .as_object()
+            .ok_or("Baseline has no \"days\" object")?;
+
+        for (day, phases) in days {
+            let day: u8 = day
+                .parse()
+                .map_err(|_| format!("Baseline has an invalid day {day:?}"))?;
+            for phase in Phase::get_phases() {
+                if let Some(median) = phases[phase.key()]["median_ns"].as_f64() {
+                    medians.insert((day, phase), median);
+                }
+            }
+        }
+
+        Ok(Self { medians })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(&self.to_json()).unwrap();
+        fs::write(path, content)
+            .map_err(|error| format!("Could not write {}: {error}", path.display()))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|error| format!("Could not read {}: {error}", path.display()))?;
+        let value: Value = serde_json::from_str(&content)
+            .map_err(|error| format!("Could not parse {}: {error}", path.display()))?;
+        Self::from_json(&value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub phase: Phase,
+    pub baseline: f64,
+    pub current: f64,
+    pub percent: f64,
+}
+
+/// Every median that got slower than the baseline by more than
+/// `threshold_percent`.
+pub fn find_regressions(
+    baseline: &Baseline,
+    current: &Baseline,
+    threshold_percent: f64,
+) -> Vec<Regression> {
+    current
+        .medians
+        .iter()
+        .filter_map(|((day, phase), current)| {
+            let baseline = *baseline.medians.get(&(*day, *phase))?;
+            if baseline <= 0.0 {
+                return None;
+            }
+            let percent = (current - baseline) / baseline * 100.0;
+            (percent > threshold_percent).then_some(Regression {
+                day: *day,
+                phase: *phase,
+                baseline,
+                current: *current,
+                percent,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod bench_test {
+    use utils::solution::Part;
+
+    use crate::bench::{find_regressions, Baseline, Phase, Stats};
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<f64> = (1..=20).map(|i| i as f64).collect();
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.samples, 20);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 10.5);
+        assert_eq!(stats.mean, 10.5);
+        assert_eq!(stats.p95, 19.0);
+        assert!((stats.std_dev - 5.916).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_stats_single_sample() {
+        let stats = Stats::from_samples(&[7.0]).unwrap();
+        assert_eq!(stats.median, 7.0);
+        assert_eq!(stats.p95, 7.0);
+        assert_eq!(stats.std_dev, 0.0);
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn test_baseline_round_trip_and_regressions() {
+        let mut baseline = Baseline::default();
+        baseline.medians.insert((1, Phase::Parse), 100.0);
+        baseline
+            .medians
+            .insert((1, Phase::Solve(Part::Two)), 1000.0);
+
+        let loaded = Baseline::from_json(&baseline.to_json()).unwrap();
+        assert_eq!(loaded, baseline);
+
+        let mut current = Baseline::default();
+        current.medians.insert((1, Phase::Parse), 105.0);
+        current.medians.insert((1, Phase::Solve(Part::Two)), 1500.0);
+        current.medians.insert((2, Phase::Parse), 50.0);
+
+        let regressions = find_regressions(&baseline, &current, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].phase, Phase::Solve(Part::Two));
+        assert_eq!(regressions[0].percent, 50.0);
+    }
+}
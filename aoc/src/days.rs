@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+
+use utils::parse::ParseError;
+use utils::solution::Solution;
+
+pub struct Day {
+    pub number: u8,
+    pub name: &'static str,
+    pub parse: fn(&str) -> Result<Box<dyn Solution>, ParseError>,
+}
+
+impl Day {
+    pub fn input_dir(&self) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(self.name)
+            .join("input")
+    }
+}
+
+macro_rules! day {
+    ($number:expr, $name:ident, $solution:ident) => {
+        Day {
+            number: $number,
+            name: stringify!($name),
+            parse: |input| Ok(Box::new(<$name::$solution as Solution>::parse(input)?)),
+        }
+    };
+}
+
+pub static DAYS: &[Day] = &[
+    day!(1, d01, Day01),
+    day!(2, d02, Day02),
+    day!(3, d03, Day03),
+    day!(4, d04, Day04),
+    day!(5, d05, Day05),
+    day!(6, d06, Day06),
+    day!(7, d07, Day07),
+    day!(8, d08, Day08),
+    day!(9, d09, Day09),
+    day!(10, d10, Day10),
+    day!(11, d11, Day11),
+    day!(12, d12, Day12),
+    day!(13, d13, Day13),
+    day!(14, d14, Day14),
+    day!(15, d15, Day15),
+    day!(16, d16, Day16),
+    day!(17, d17, Day17),
+    day!(18, d18, Day18),
+    day!(19, d19, Day19),
+    day!(20, d20, Day20),
+    day!(21, d21, Day21),
+    day!(22, d22, Day22),
+    day!(23, d23, Day23),
+    day!(24, d24, Day24),
+    day!(25, d25, Day25),
+];
+
+pub fn get_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,117 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::answers::ANSWERS_FILE;
+use crate::days::Day;
+
+pub const STDIN: &str = "-";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Resolves `spec` for `day`. `-` is stdin, an existing path is used as
+    /// is, anything else names a file in the day's `input/` folder: `test`
+    /// finds `test`, `test.txt` or `input_test.txt`.
+    pub fn resolve(day: &Day, spec: &str) -> Result<Self, String> {
+        if spec == STDIN {
+            return Ok(InputSource::Stdin);
+        }
+
+        let path = Path::new(spec);
+        if path.is_file() {
+            return Ok(InputSource::File(path.to_path_buf()));
+        }
+
+        let input_dir = day.input_dir();
+        let candidates = [
+            spec.to_string(),
+            format!("{spec}.txt"),
+            format!("input_{spec}.txt"),
+        ];
+
+        candidates
+            .iter()
+            .map(|candidate| input_dir.join(candidate))
+            .find(|candidate| candidate.is_file())
+            .map(InputSource::File)
+            .ok_or_else(|| {
+                format!(
+                    "No input {spec:?} for day {}, available inputs: {}",
+                    day.number,
+                    list_inputs(day).join(", ")
+                )
+            })
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            InputSource::Stdin => STDIN.to_string(),
+            InputSource::File(path) => path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| path.to_string_lossy().to_string()),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|error| format!("Could not read stdin: {error}"))?;
+                Ok(input)
+            }
+            InputSource::File(path) => fs::read_to_string(path)
+                .map_err(|error| format!("Could not read {}: {error}", path.display())),
+        }
+    }
+}
+
+pub fn list_inputs(day: &Day) -> Vec<String> {
+    let mut rtn: Vec<String> = fs::read_dir(day.input_dir())
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_file())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .filter(|name| name != ANSWERS_FILE)
+                .collect()
+        })
+        .unwrap_or_default();
+    rtn.sort();
+    rtn
+}
+
+#[cfg(test)]
+mod input_test {
+    use crate::days::get_day;
+    use crate::input::InputSource;
+
+    #[test]
+    fn test_resolve_named_input() {
+        let day = get_day(18).unwrap();
+        let source = InputSource::resolve(day, "test_2").unwrap();
+        assert_eq!(source.name(), "input_test_2.txt");
+
+        let source = InputSource::resolve(day, "input").unwrap();
+        assert_eq!(source.name(), "input.txt");
+    }
+
+    #[test]
+    fn test_resolve_stdin() {
+        let day = get_day(1).unwrap();
+        assert_eq!(InputSource::resolve(day, "-"), Ok(InputSource::Stdin));
+    }
+
+    #[test]
+    fn test_resolve_missing_input() {
+        let day = get_day(1).unwrap();
+        assert!(InputSource::resolve(day, "missing").is_err());
+    }
+}
@@ -0,0 +1,7 @@
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod input;
+pub mod output;
+pub mod runner;
+pub mod scaffold;
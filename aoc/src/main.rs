@@ -0,0 +1,318 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc::answers::verify_day;
+use aoc::bench::{bench_day, find_regressions, format_ns, Baseline, BenchConfig, DayBench};
+use aoc::days::{get_day, Day, DAYS};
+use aoc::input::{list_inputs, InputSource};
+use aoc::output::{answers_to_json, answers_to_text, Format};
+use aoc::scaffold::{create_day, workspace_root, Template};
+use aoc::runner::set_params;
+use clap::{Parser, Subcommand};
+use utils::params::Param;
+use utils::solution::Part;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2017 in Rust")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or both parts of a day
+    Run {
+        #[arg(short, long)]
+        day: u8,
+        /// Part to solve, both parts when omitted
+        #[arg(short, long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// A path, `-` for stdin, or the name of a file in the day's input folder
+        #[arg(short, long, default_value = "input")]
+        input: String,
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Override a parameter of the day, on top of those the answers file
+        /// lists for the input
+        #[arg(long = "param", value_name = "NAME=VALUE")]
+        params: Vec<Param>,
+    },
+    /// List the days and their inputs
+    List,
+    /// Time parsing and both parts of the days
+    Bench {
+        /// Only benchmark these days, all days when omitted
+        #[arg(short, long)]
+        day: Vec<u8>,
+        #[arg(short, long, default_value = "input")]
+        input: String,
+        /// Override a parameter of the benchmarked days
+        #[arg(long = "param", value_name = "NAME=VALUE")]
+        params: Vec<Param>,
+        /// Untimed runs before measuring
+        #[arg(short, long, default_value_t = 3)]
+        warmup: usize,
+        /// Timed runs per phase
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+        /// Write the medians of this run to a baseline file
+        #[arg(long)]
+        save_baseline: Option<PathBuf>,
+        /// Compare the medians of this run against a baseline file
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Slowdown in percent above which a phase counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Create a new day crate from the templates and add it to the workspace
+    New {
+        #[arg(short, long)]
+        day: u8,
+        /// Start from the template for inputs that are a single line
+        #[arg(long)]
+        single_line: bool,
+        /// Workspace to create the day in, this workspace when omitted
+        #[arg(long)]
+        root: Option<PathBuf>,
+    },
+    /// Check the answers listed in the days' `input/answers.toml`
+    Verify {
+        /// Only verify these days, all days when omitted
+        #[arg(short, long)]
+        day: Vec<u8>,
+    },
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    let number: u8 = value
+        .parse()
+        .map_err(|_| format!("Not a part number: {value}"))?;
+    Part::try_from(number)
+}
+
+fn run(
+    day: u8,
+    part: Option<Part>,
+    input: &str,
+    format: Format,
+    params: &[Param],
+) -> Result<(), String> {
+    let day = get_day(day).ok_or_else(|| format!("There is no day {day}"))?;
+    let source = InputSource::resolve(day, input)?;
+    let input = source.read()?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::get_parts().to_vec(),
+    };
+
+    let mut solution =
+        (day.parse)(&input).map_err(|error| error.render(&source.name(), &input))?;
+    set_params(day, &source.name(), &mut *solution, params).map_err(|error| error.to_string())?;
+    let answers: Vec<_> = parts
+        .into_iter()
+        .map(|part| (part, solution.solve(part)))
+        .collect();
+
+    match format {
+        Format::Text => println!("{}", answers_to_text(day.number, &answers)),
+        Format::Json => println!("{}", answers_to_json(day.number, &source.name(), &answers)),
+    }
+
+    Ok(())
+}
+
+fn list() {
+    for day in DAYS.iter() {
+        println!("Day {:02}: {}", day.number, list_inputs(day).join(", "));
+    }
+}
+
+fn select_days(days: &[u8]) -> Result<Vec<&'static Day>, String> {
+    if days.is_empty() {
+        return Ok(DAYS.iter().collect());
+    }
+
+    days.iter()
+        .map(|number| get_day(*number).ok_or_else(|| format!("There is no day {number}")))
+        .collect()
+}
+
+fn print_bench(bench: &DayBench) {
+    for (phase, stats) in bench.phases.iter() {
+        println!(
+            "Day {:02} {:<5} min {:>10}  median {:>10}  mean {:>10}  p95 {:>10}  stddev {:>10}",
+            bench.day,
+            phase,
+            format_ns(stats.min),
+            format_ns(stats.median),
+            format_ns(stats.mean),
+            format_ns(stats.p95),
+            format_ns(stats.std_dev)
+        );
+    }
+}
+
+fn bench(
+    days: &[u8],
+    input: &str,
+    params: &[Param],
+    config: BenchConfig,
+    save_baseline: Option<&Path>,
+    baseline: Option<&Path>,
+    threshold: f64,
+) -> Result<(), String> {
+    let days = select_days(days)?;
+    let baseline = baseline.map(Baseline::load).transpose()?;
+
+    let mut benches = Vec::new();
+    let mut failed = Vec::new();
+    for day in days {
+        match bench_day(day, input, params, &config) {
+            Ok(bench) => {
+                print_bench(&bench);
+                benches.push(bench);
+            }
+            Err(failure) => {
+                println!("Day {:02} failed - {failure}", day.number);
+                failed.push(format!("{:02}", day.number));
+            }
+        }
+    }
+
+    let current = Baseline::from_benches(&benches);
+    if let Some(path) = save_baseline {
+        current.save(path)?;
+        println!("Saved baseline to {}", path.display());
+    }
+
+    let mut errors = Vec::new();
+    if let Some(baseline) = baseline {
+        let regressions = find_regressions(&baseline, &current, threshold);
+        for regression in regressions.iter() {
+            println!(
+                "Regression: day {:02} {} {} -> {} (+{:.1}%)",
+                regression.day,
+                regression.phase,
+                format_ns(regression.baseline),
+                format_ns(regression.current),
+                regression.percent
+            );
+        }
+        if !regressions.is_empty() {
+            errors.push(format!(
+                "{} regressions above {threshold}%",
+                regressions.len()
+            ));
+        }
+    }
+    if !failed.is_empty() {
+        errors.push(format!("Failed days: {}", failed.join(", ")));
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+fn new(day: u8, single_line: bool, root: Option<PathBuf>) -> Result<(), String> {
+    let template = if single_line {
+        Template::SingleLine
+    } else {
+        Template::Lines
+    };
+    let root = root.unwrap_or_else(workspace_root);
+
+    for path in create_day(&root, day, template)? {
+        println!("Created {}", path.display());
+    }
+    println!("Added d{day:02} to the workspace members and the aoc day table");
+
+    Ok(())
+}
+
+fn verify(days: &[u8]) -> Result<(), String> {
+    let mut checked = 0;
+    let mut failed = 0;
+
+    for day in select_days(days)? {
+        for verification in verify_day(day)? {
+            let expected = &verification.expected;
+            let status = match &verification.actual {
+                _ if verification.is_success() => "ok".to_string(),
+                Ok(actual) => format!("FAILED, expected {} got {actual}", expected.answer),
+                Err(failure) => format!("FAILED, {failure}"),
+            };
+            println!(
+                "Day {:02} {} part {}: {status}",
+                verification.day, expected.input, expected.part
+            );
+
+            checked += 1;
+            if !verification.is_success() {
+                failed += 1;
+            }
+        }
+    }
+
+    println!("Checked {checked} answers, {failed} failed");
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(format!("{failed} answers did not match"))
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            params,
+        } => run(day, part, &input, format, &params),
+        Command::List => {
+            list();
+            Ok(())
+        }
+        Command::Bench {
+            day,
+            input,
+            params,
+            warmup,
+            iterations,
+            save_baseline,
+            baseline,
+            threshold,
+        } => bench(
+            &day,
+            &input,
+            &params,
+            BenchConfig { warmup, iterations },
+            save_baseline.as_deref(),
+            baseline.as_deref(),
+            threshold,
+        ),
+        Command::New {
+            day,
+            single_line,
+            root,
+        } => new(day, single_line, root),
+        Command::Verify { day } => verify(&day),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use clap::ValueEnum;
+use serde_json::{json, Value};
+use utils::solution::{Answer, Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+pub fn answer_to_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Number(value) => json!(value),
+        Answer::Text(value) => json!(value),
+        Answer::Empty => Value::Null,
+    }
+}
+
+pub fn answers_to_json(day: u8, input: &str, answers: &[(Part, Answer)]) -> Value {
+    json!({
+        "day": day,
+        "input": input,
+        "answers": answers
+            .iter()
+            .map(|(part, answer)| json!({
+                "part": part.number(),
+                "answer": answer_to_json(answer),
+            }))
+            .collect::<Vec<Value>>(),
+    })
+}
+
+pub fn answers_to_text(day: u8, answers: &[(Part, Answer)]) -> String {
+    answers
+        .iter()
+        .map(|(part, answer)| format!("Day {day:02} part {part}: {answer}"))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
@@ -0,0 +1,455 @@
+use std::cell::{Cell, RefCell};
+use std::env::consts::EXE_SUFFIX;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use utils::params::{apply_params, Param};
+use utils::parse::ParseError;
+use utils::solution::{Answer, Part, Solution};
+
+use crate::answers::input_params;
+use crate::days::Day;
+use crate::input::InputSource;
+
+#[derive(Debug, Clone)]
+pub enum Failure {
+    Input(String),
+    Parse(ParseError),
+    Param(String),
+    Panic(String),
+    Spawn(String),
+    Exit(Option<i32>),
+    NoAnswers,
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Input(error) => write!(f, "input error: {error}"),
+            Failure::Parse(error) => write!(f, "parse error: {error}"),
+            Failure::Param(error) => write!(f, "parameter error: {error}"),
+            Failure::Panic(message) => write!(f, "panicked: {message}"),
+            Failure::Spawn(error) => write!(f, "could not start: {error}"),
+            Failure::Exit(Some(code)) => write!(f, "exited with status {code}"),
+            Failure::Exit(None) => write!(f, "terminated by a signal"),
+            Failure::NoAnswers => write!(f, "printed no answers"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DayReport {
+    pub day: u8,
+    pub answers: Result<Vec<(Part, Answer)>, Failure>,
+    pub stderr: String,
+    pub duration: Duration,
+}
+
+impl DayReport {
+    pub fn is_success(&self) -> bool {
+        self.answers.is_ok()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Mode {
+    InProcess { input: String },
+    Subprocess { binary_dir: PathBuf, input: String },
+}
+
+thread_local! {
+    static CAPTURE_PANIC: Cell<bool> = const { Cell::new(false) };
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static INSTALL_PANIC_HOOK: Once = Once::new();
+
+/// Panics of solutions run through `run_in_process` are recorded for their
+/// report, every other panic still goes to the default hook.
+fn install_panic_hook() {
+    INSTALL_PANIC_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CAPTURE_PANIC.with(|capture| capture.get()) {
+                LAST_PANIC.with(|last| *last.borrow_mut() = Some(info.to_string()));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+/// Runs `f` and turns a panic into a `Failure`. The panic output can be
+/// fetched with `take_panic_output` on the same thread.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, Failure> {
+    install_panic_hook();
+
+    CAPTURE_PANIC.with(|capture| capture.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CAPTURE_PANIC.with(|capture| capture.set(false));
+
+    result.map_err(|payload| Failure::Panic(panic_message(payload)))
+}
+
+pub fn take_panic_output() -> String {
+    LAST_PANIC
+        .with(|last| last.borrow_mut().take())
+        .unwrap_or_default()
+}
+
+/// Like `catch_panic`, but a panic is reported with the full hook output,
+/// which includes where it happened.
+pub fn catch_panic_with_location<T>(f: impl FnOnce() -> T) -> Result<T, Failure> {
+    catch_panic(f).map_err(|failure| match failure {
+        Failure::Panic(message) => {
+            let output = take_panic_output();
+            Failure::Panic(if output.is_empty() { message } else { output })
+        }
+        other => other,
+    })
+}
+
+/// Sets the parameters the answers file of `day` lists for the input `name`,
+/// then `overrides` on top of them.
+pub fn set_params(
+    day: &Day,
+    name: &str,
+    solution: &mut dyn Solution,
+    overrides: &[Param],
+) -> Result<(), Failure> {
+    let mut params = input_params(day, name).map_err(Failure::Param)?;
+    params.extend_from_slice(overrides);
+    apply_params(solution, &params).map_err(Failure::Param)
+}
+
+/// Parses the input and solves both parts inside this process. A panic in the
+/// solution is caught and reported instead of taking down the runner.
+pub fn run_in_process(day: &Day, input: &str) -> DayReport {
+    let start = Instant::now();
+
+    let mut stderr = String::new();
+
+    let answers = InputSource::resolve(day, input)
+        .and_then(|source| Ok((source.name(), source.read()?)))
+        .map_err(Failure::Input)
+        .and_then(|(name, input)| {
+            let mut solution = catch_panic(|| (day.parse)(&input))?.map_err(|error| {
+                stderr = error.render(&name, &input);
+                Failure::Parse(error)
+            })?;
+            set_params(day, &name, &mut *solution, &[])?;
+
+            catch_panic(|| {
+                Part::get_parts()
+                    .into_iter()
+                    .map(|part| (part, solution.solve(part)))
+                    .collect::<Vec<_>>()
+            })
+        });
+
+    if stderr.is_empty() {
+        stderr = take_panic_output();
+    }
+
+    DayReport {
+        day: day.number,
+        answers,
+        stderr,
+        duration: start.elapsed(),
+    }
+}
+
+/// Runs the day's release binary from `binary_dir` on the resolved `input`
+/// and reads the answers from its output. Stdin can not be handed to the
+/// binaries, they may run several at a time.
+pub fn run_subprocess(day: &Day, binary_dir: &Path, input: &str) -> DayReport {
+    let start = Instant::now();
+    let binary = binary_dir.join(format!("{}{}", day.name, EXE_SUFFIX));
+
+    let path = match InputSource::resolve(day, input) {
+        Ok(InputSource::File(path)) => Ok(path),
+        Ok(InputSource::Stdin) => Err(Failure::Input(
+            "stdin can not be used in subprocess mode".to_string(),
+        )),
+        Err(error) => Err(Failure::Input(error)),
+    };
+
+    let (answers, stderr) = match path.map(|path| Command::new(&binary).arg(path).output()) {
+        Ok(Ok(output)) => {
+            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            let answers = if output.status.success() {
+                let answers = parse_answers(&String::from_utf8_lossy(&output.stdout));
+                if answers.is_empty() {
+                    Err(Failure::NoAnswers)
+                } else {
+                    Ok(answers)
+                }
+            } else {
+                Err(Failure::Exit(output.status.code()))
+            };
+            (answers, stderr)
+        }
+        Ok(Err(error)) => (
+            Err(Failure::Spawn(format!("{}: {error}", binary.display()))),
+            String::new(),
+        ),
+        Err(failure) => (Err(failure), String::new()),
+    };
+
+    DayReport {
+        day: day.number,
+        answers,
+        stderr,
+        duration: start.elapsed(),
+    }
+}
+
+/// Runs `days` on `threads` worker threads. The reports keep the order of
+/// `days`.
+pub fn run_days(days: &[&Day], mode: &Mode, threads: usize) -> Vec<DayReport> {
+    let next = AtomicUsize::new(0);
+    let reports: Mutex<Vec<Option<DayReport>>> =
+        Mutex::new((0..days.len()).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, days.len().max(1)) {
+            scope.spawn(|| loop {
+                let idx = next.fetch_add(1, Ordering::SeqCst);
+                let Some(day) = days.get(idx) else {
+                    break;
+                };
+
+                let report = match mode {
+                    Mode::InProcess { input } => run_in_process(day, input),
+                    Mode::Subprocess { binary_dir, input } => {
+                        run_subprocess(day, binary_dir, input)
+                    }
+                };
+
+                reports.lock().unwrap()[idx] = Some(report);
+            });
+        }
+    });
+
+    reports
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|report| report.unwrap())
+        .collect()
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic payload")
+    }
+}
+
+fn parse_answers(stdout: &str) -> Vec<(Part, Answer)> {
+    let mut rtn = Vec::new();
+
+    for line in stdout.lines() {
+        let Some(rest) = line.strip_prefix("Result of part ") else {
+            continue;
+        };
+        let Some((part, answer)) = rest.split_once(" is ") else {
+            continue;
+        };
+        let Some(part) = part.parse::<u8>().ok().and_then(|p| Part::try_from(p).ok()) else {
+            continue;
+        };
+
+        let answer = match answer.parse::<i64>() {
+            Ok(value) => Answer::Number(value),
+            Err(_) if answer == "-" => Answer::Empty,
+            Err(_) => Answer::Text(answer.to_string()),
+        };
+        rtn.push((part, answer));
+    }
+
+    rtn
+}
+
+#[cfg(test)]
+mod runner_test {
+    use std::env::temp_dir;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process;
+
+    use utils::solution::{Answer, Part};
+
+    use crate::days::get_day;
+    use crate::input::InputSource;
+    use crate::runner::{
+        catch_panic, catch_panic_with_location, parse_answers, run_days, take_panic_output,
+        Failure, Mode,
+    };
+
+    /// A path in the temp folder that no other test process uses.
+    fn temp_path(name: &str) -> PathBuf {
+        temp_dir().join(format!("{}_{name}", process::id()))
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let answers =
+            parse_answers("\"input.txt\"\nResult of part 1 is 42\nResult of part 2 is abc\n");
+        assert_eq!(
+            answers,
+            vec![
+                (Part::One, Answer::Number(42)),
+                (Part::Two, Answer::Text("abc".to_string()))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_run_days_in_process() {
+        let days = [
+            get_day(1).unwrap(),
+            get_day(2).unwrap(),
+            get_day(12).unwrap(),
+        ];
+        let mode = Mode::InProcess {
+            input: "test".to_string(),
+        };
+        let reports = run_days(&days, &mode, 2);
+
+        assert_eq!(
+            reports.iter().map(|r| r.day).collect::<Vec<_>>(),
+            vec![1, 2, 12]
+        );
+        assert!(reports.iter().all(|r| r.is_success()));
+    }
+
+    #[test]
+    fn test_run_days_reports_bad_digit() {
+        let input_path = temp_path("aoc_runner_test_input.txt");
+        fs::write(&input_path, "12x4").unwrap();
+
+        let days = [get_day(1).unwrap()];
+        let mode = Mode::InProcess {
+            input: input_path.to_string_lossy().to_string(),
+        };
+        let reports = run_days(&days, &mode, 1);
+        fs::remove_file(&input_path).unwrap();
+
+        let Err(Failure::Parse(error)) = &reports[0].answers else {
+            panic!("Expected a parse error");
+        };
+        assert_eq!((error.line, error.column), (1, 3));
+        assert!(reports[0].stderr.contains("1 | 12x4"));
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        let result = catch_panic(|| -> u8 { panic!("boom") });
+        let Err(Failure::Panic(message)) = result else {
+            panic!("Expected a panic");
+        };
+        assert_eq!(message, "boom");
+        assert!(take_panic_output().contains("boom"));
+        assert_eq!(take_panic_output(), "");
+
+        assert!(matches!(catch_panic(|| 1), Ok(1)));
+        let Err(Failure::Panic(output)) = catch_panic_with_location(|| -> u8 { panic!("bang") })
+        else {
+            panic!("Expected a panic");
+        };
+        assert!(output.contains("runner.rs"));
+    }
+
+    #[test]
+    fn test_run_days_reports_parse_error() {
+        let input_path = temp_path("aoc_runner_test_parse_input.txt");
+        fs::write(&input_path, "b inc 5 if a > 1\na jump 1 if b < 5\n").unwrap();
+
+        let days = [get_day(8).unwrap()];
+        let mode = Mode::InProcess {
+            input: input_path.to_string_lossy().to_string(),
+        };
+        let reports = run_days(&days, &mode, 1);
+        fs::remove_file(&input_path).unwrap();
+
+        let Err(Failure::Parse(error)) = &reports[0].answers else {
+            panic!("Expected a parse error");
+        };
+        assert_eq!((error.line, error.column), (2, 3));
+        assert!(reports[0].stderr.contains("2 | a jump 1 if b < 5"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_days_subprocess() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let binary_dir = temp_path("aoc_runner_test_binaries");
+        fs::create_dir_all(&binary_dir).unwrap();
+        for (name, script) in [
+            ("d01", "#!/bin/sh\necho \"Result of part 1 is $1\"\n"),
+            ("d02", "#!/bin/sh\necho \"$1\"\n"),
+        ] {
+            let binary = binary_dir.join(name);
+            fs::write(&binary, script).unwrap();
+            fs::set_permissions(&binary, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        let days = [get_day(1).unwrap(), get_day(2).unwrap()];
+        let mode = Mode::Subprocess {
+            binary_dir: binary_dir.clone(),
+            input: "test".to_string(),
+        };
+        let reports = run_days(&days, &mode, 1);
+
+        let Ok(answers) = &reports[0].answers else {
+            panic!("Expected answers");
+        };
+        assert!(answers[0].1.to_string().ends_with("input_test.txt"));
+        assert!(matches!(reports[1].answers, Err(Failure::NoAnswers)));
+
+        let mode = Mode::Subprocess {
+            binary_dir: binary_dir.clone(),
+            input: "-".to_string(),
+        };
+        let reports = run_days(&days[..1], &mode, 1);
+        assert!(matches!(reports[0].answers, Err(Failure::Input(_))));
+
+        fs::remove_dir_all(&binary_dir).unwrap();
+    }
+
+    #[test]
+    fn test_set_param_out_of_range() {
+        for (number, name, value, accepted) in [
+            (10, "list_size", "0", false),
+            (10, "list_size", "1", false),
+            (10, "list_size", "4", false),
+            (10, "list_size", "257", false),
+            (10, "list_size", "256", true),
+            (16, "dancers", "4", false),
+            (16, "dancers", "27", false),
+            (16, "dancers", "26", true),
+        ] {
+            let day = get_day(number).unwrap();
+            let input = InputSource::resolve(day, "test").unwrap().read().unwrap();
+            let mut solution = (day.parse)(&input).unwrap();
+            let before = solution.params();
+
+            let result = solution.set_param(name, value);
+            assert_eq!(result.is_ok(), accepted, "day {number} {name}={value}");
+            if !accepted {
+                assert_eq!(solution.params(), before);
+            }
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE_DIR: &str = "template";
+const PLACEHOLDER_CRATE: &str = "d00";
+const PLACEHOLDER_STRUCT: &str = "Day00";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Template {
+    Lines,
+    SingleLine,
+}
+
+impl Template {
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Template::Lines => "main.rs",
+            Template::SingleLine => "main_single_line.rs",
+        }
+    }
+}
+
+/// The workspace this binary was built in.
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|error| format!("Could not read {}: {error}", path.display()))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|error| format!("Could not write {}: {error}", path.display()))
+}
+
+fn day_number(name: &str) -> Option<u8> {
+    let number = name.strip_prefix('d')?;
+    if number.len() == 2 && number.chars().all(|c| c.is_ascii_digit()) {
+        number.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Inserts `line` into the block of `content` between the line `is_start`
+/// accepts and the line `is_end` accepts, or the end of `content`. It goes
+/// after the last line whose day, as read by `day_of`, comes before `day`.
+fn insert_day_line(
+    content: &str,
+    what: &str,
+    is_start: impl Fn(&str) -> bool,
+    is_end: impl Fn(&str) -> bool,
+    day_of: impl Fn(&str) -> Option<u8>,
+    day: u8,
+    line: String,
+) -> Result<String, String> {
+    let mut lines: Vec<String> = content.lines().map(|line| line.to_string()).collect();
+
+    let start = lines
+        .iter()
+        .position(|line| is_start(line))
+        .ok_or(format!("Could not find the {what}"))?;
+    let end = lines[start + 1..]
+        .iter()
+        .position(|line| is_end(line))
+        .map(|offset| start + 1 + offset)
+        .unwrap_or(lines.len());
+
+    if lines[start + 1..end]
+        .iter()
+        .any(|line| day_of(line) == Some(day))
+    {
+        return Err(format!("d{day:02} is already in the {what}"));
+    }
+
+    let insert_at = (start + 1..end)
+        .rev()
+        .find(|i| day_of(&lines[*i]).is_some_and(|other| other < day))
+        .map(|i| i + 1)
+        .unwrap_or(start + 1);
+
+    lines.insert(insert_at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts `name` into the `members` list of a workspace manifest, after the
+/// last day crate that sorts before it.
+pub fn add_workspace_member(manifest: &str, name: &str) -> Result<String, String> {
+    let day = day_number(name).ok_or(format!("{name} is not a day crate"))?;
+    insert_day_line(
+        manifest,
+        "members list of the workspace manifest",
+        |line| line.trim_start().starts_with("members = ["),
+        |line| line.trim() == "]",
+        |line| day_number(line.trim().trim_end_matches(',').trim_matches('"')),
+        day,
+        format!("  \"{name}\","),
+    )
+}
+
+/// Adds the day crate `name` to the dependencies of the `aoc` manifest.
+pub fn add_day_dependency(manifest: &str, name: &str) -> Result<String, String> {
+    let day = day_number(name).ok_or(format!("{name} is not a day crate"))?;
+    insert_day_line(
+        manifest,
+        "dependencies of the aoc manifest",
+        |line| line.trim() == "[dependencies]",
+        |line| line.starts_with('['),
+        |line| day_number(line.split_once('=')?.0.trim()),
+        day,
+        format!("{name} = {{path = \"../{name}\"}}"),
+    )
+}
+
+/// Adds the day crate `name` to the `DAYS` table of `aoc/src/days.rs`.
+pub fn add_day_entry(days: &str, name: &str) -> Result<String, String> {
+    let day = day_number(name).ok_or(format!("{name} is not a day crate"))?;
+    insert_day_line(
+        days,
+        "DAYS table",
+        |line| line.starts_with("pub static DAYS"),
+        |line| line.trim() == "];",
+        |line| {
+            line.trim()
+                .strip_prefix("day!(")?
+                .split(',')
+                .next()?
+                .parse()
+                .ok()
+        },
+        day,
+        format!("    day!({day}, {name}, Day{day:02}),"),
+    )
+}
+
+/// Creates the crate `dNN` in `root` from the templates and registers it in
+/// the workspace and in the day table of `aoc`. Returns the created files.
+pub fn create_day(root: &Path, day: u8, template: Template) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("There is no day {day}"));
+    }
+
+    let name = format!("d{day:02}");
+    let struct_name = format!("Day{day:02}");
+    let day_dir = root.join(&name);
+    if day_dir.exists() {
+        return Err(format!("{} already exists", day_dir.display()));
+    }
+
+    let template_dir = root.join(TEMPLATE_DIR);
+    let replace = |content: String| {
+        content
+            .replace(PLACEHOLDER_STRUCT, &struct_name)
+            .replace(PLACEHOLDER_CRATE, &name)
+    };
+    let lib = replace(read(&template_dir.join(template.file_name()))?);
+    let cargo_toml = replace(read(&template_dir.join("cargo.toml"))?);
+    let input = read(&template_dir.join("input.txt"))?;
+
+    let workspace_manifest = root.join("Cargo.toml");
+    let members = add_workspace_member(&read(&workspace_manifest)?, &name)?;
+    let aoc_manifest = root.join("aoc").join("Cargo.toml");
+    let dependencies = add_day_dependency(&read(&aoc_manifest)?, &name)?;
+    let days_path = root.join("aoc").join("src").join("days.rs");
+    let days = add_day_entry(&read(&days_path)?, &name)?;
+
+    let src_dir = day_dir.join("src");
+    let input_dir = day_dir.join("input");
+    for dir in [&src_dir, &input_dir] {
+        fs::create_dir_all(dir)
+            .map_err(|error| format!("Could not create {}: {error}", dir.display()))?;
+    }
+
+    let main = format!(
+        "use {name}::{struct_name};\nuse utils::solution::run_solution;\n\nfn main() {{\n    run_solution::<{struct_name}>(file!());\n}}\n"
+    );
+    let answers = "[\"input_test.txt\"]\n# part1 = 0\n# part2 = 0\n".to_string();
+
+    let files = [
+        (day_dir.join("Cargo.toml"), cargo_toml),
+        (src_dir.join("lib.rs"), lib),
+        (src_dir.join("main.rs"), main),
+        (input_dir.join("input.txt"), input),
+        (input_dir.join("input_test.txt"), String::new()),
+        (input_dir.join("answers.toml"), answers),
+    ];
+    for (path, content) in files.iter() {
+        write(path, content)?;
+    }
+    write(&workspace_manifest, &members)?;
+    write(&aoc_manifest, &dependencies)?;
+    write(&days_path, &days)?;
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod scaffold_test {
+    use std::env::temp_dir;
+    use std::fs;
+    use std::process;
+
+    use crate::scaffold::{
+        add_day_dependency, add_day_entry, add_workspace_member, create_day, workspace_root,
+        Template,
+    };
+
+    const MANIFEST: &str =
+        "[workspace]\nresolver = \"2\"\nmembers = [\n  \"d01\",\n  \"d03\",\n  \"utils\",\n]\n";
+    const AOC_MANIFEST: &str = "[package]\nname = \"aoc\"\n\n[dependencies]\nutils = {path = \"../utils\"}\nd01 = {path = \"../d01\"}\nd03 = {path = \"../d03\"}\n\n[build-dependencies]\ntoml = \"0.9\"\n";
+    const DAYS: &str =
+        "pub static DAYS: &[Day] = &[\n    day!(1, d01, Day01),\n    day!(3, d03, Day03),\n];\n";
+
+    #[test]
+    fn test_add_workspace_member() {
+        let manifest = add_workspace_member(MANIFEST, "d02").unwrap();
+        assert!(manifest.contains("  \"d01\",\n  \"d02\",\n  \"d03\",\n"));
+
+        let manifest = add_workspace_member(MANIFEST, "d04").unwrap();
+        assert!(manifest.contains("  \"d03\",\n  \"d04\",\n  \"utils\",\n"));
+
+        assert!(add_workspace_member(MANIFEST, "d03").is_err());
+        assert!(add_workspace_member("[workspace]\n", "d02").is_err());
+        assert!(add_workspace_member(MANIFEST, "utils").is_err());
+    }
+
+    #[test]
+    fn test_add_day_dependency() {
+        let manifest = add_day_dependency(AOC_MANIFEST, "d02").unwrap();
+        assert!(manifest.contains(
+            "d01 = {path = \"../d01\"}\nd02 = {path = \"../d02\"}\nd03 = {path = \"../d03\"}\n"
+        ));
+
+        let manifest = add_day_dependency(AOC_MANIFEST, "d04").unwrap();
+        assert!(manifest.contains("d03 = {path = \"../d03\"}\nd04 = {path = \"../d04\"}\n\n"));
+
+        assert!(add_day_dependency(AOC_MANIFEST, "d01").is_err());
+    }
+
+    #[test]
+    fn test_add_day_entry() {
+        let days = add_day_entry(DAYS, "d02").unwrap();
+        assert!(days.contains("day!(1, d01, Day01),\n    day!(2, d02, Day02),\n    day!(3"));
+
+        let days = add_day_entry(DAYS, "d10").unwrap();
+        assert!(days.contains("day!(3, d03, Day03),\n    day!(10, d10, Day10),\n];"));
+
+        assert!(add_day_entry(DAYS, "d03").is_err());
+    }
+
+    #[test]
+    fn test_current_day_table() {
+        let days = fs::read_to_string(workspace_root().join("aoc/src/days.rs")).unwrap();
+        assert!(add_day_entry(&days, "d25").is_err());
+        let manifest = fs::read_to_string(workspace_root().join("aoc/Cargo.toml")).unwrap();
+        assert!(add_day_dependency(&manifest, "d25").is_err());
+    }
+
+    #[test]
+    fn test_create_day() {
+        let root = temp_dir().join(format!("aoc_scaffold_test_{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("template")).unwrap();
+        for file in ["main.rs", "main_single_line.rs", "cargo.toml", "input.txt"] {
+            fs::copy(
+                workspace_root().join("template").join(file),
+                root.join("template").join(file),
+            )
+            .unwrap();
+        }
+        fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), AOC_MANIFEST).unwrap();
+        fs::write(root.join("aoc/src/days.rs"), DAYS).unwrap();
+
+        let files = create_day(&root, 2, Template::SingleLine).unwrap();
+        assert_eq!(files.len(), 6);
+
+        let lib = fs::read_to_string(root.join("d02/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day02"));
+        assert!(!lib.contains("Day00"));
+        let cargo_toml = fs::read_to_string(root.join("d02/Cargo.toml")).unwrap();
+        assert!(cargo_toml.contains("name = \"d02\""));
+        assert!(cargo_toml.contains("utils = {path = \"../utils\"}"));
+        let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("  \"d02\",\n"));
+        let aoc_manifest = fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+        assert!(aoc_manifest.contains("d02 = {path = \"../d02\"}"));
+        let days = fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        assert!(days.contains("day!(2, d02, Day02),"));
+
+        assert!(create_day(&root, 2, Template::Lines).is_err());
+        assert!(create_day(&root, 26, Template::Lines).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -9,9 +9,8 @@ fn main() {
         .filter(|f| f.file_name() == "input.txt")
     {
         println!("{:?}", entry.path().as_os_str());
-        fs::write(entry.path(), "your input here").expect(&format!(
-            "Failed to write to file: {:?}",
-            entry.path().as_os_str()
-        ));
+        fs::write(entry.path(), "your input here").unwrap_or_else(|_| {
+            panic!("Failed to write to file: {:?}", entry.path().as_os_str())
+        });
     }
 }
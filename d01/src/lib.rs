@@ -0,0 +1,78 @@
+use utils::parse::{parse_token, ParseError};
+use utils::solution::{Answer, Solution};
+
+pub struct Day01 {
+    line: Vec<u32>,
+}
+
+impl Solution for Day01 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let line = input.trim();
+        let line = line
+            .char_indices()
+            .map(|(i, c)| parse_token(input, &line[i..i + c.len_utf8()]))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { line })
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let mut result: u32 = 0;
+        let line = &self.line;
+
+        // Solve
+        for i in 0..line.len() {
+            let mut p2 = i + 1;
+            if p2 == line.len() {
+                p2 = 0;
+            }
+
+            if line[i] == line[p2] {
+                result += line[i];
+            }
+        }
+
+        // Result
+        result.into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Preamble
+        let mut result: u32 = 0;
+        let line = &self.line;
+        let helf_len = line.len() / 2;
+
+        // Solve
+        for i in 0..line.len() {
+            let p2 = (i + helf_len) % line.len();
+
+            if line[i] == line[p2] {
+                result += line[i];
+            }
+        }
+
+        // Result
+        result.into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day01;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day01::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day01::parse(input.text()).unwrap().part2());
+    }
+}
@@ -0,0 +1,90 @@
+use utils::parse::{parse_token, ParseError};
+use utils::solution::{Answer, Solution};
+
+pub struct Day02 {
+    rows: Vec<Vec<usize>>,
+}
+
+impl Solution for Day02 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut rows = Vec::new();
+
+        for line in input.lines().filter(|line| !line.trim().is_empty()) {
+            let row: Vec<usize> = line
+                .split_whitespace()
+                .map(|f| parse_token(input, f))
+                .collect::<Result<_, _>>()?;
+            rows.push(row);
+        }
+
+        Ok(Self { rows })
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let mut result = 0;
+
+        // Solve
+        for row in self.rows.iter() {
+            let mut min = usize::MAX;
+            let mut max = usize::MIN;
+
+            for i in row {
+                min = min.min(*i);
+                max = max.max(*i);
+            }
+
+            let to_add = max - min;
+            result += to_add;
+        }
+
+        // Result
+        result.into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Preamble
+        let mut result = 0;
+
+        // Solve
+        for row in self.rows.iter() {
+            let mut row = row.clone();
+            row.sort_unstable_by(|l, r| r.cmp(l));
+
+            for p1 in 0..row.len() {
+                for p2 in (p1 + 1..row.len()).rev() {
+                    let z1 = row[p1];
+                    let z2 = row[p2];
+                    if z1 % z2 == 0 {
+                        let to_add = z1 / z2;
+                        result += to_add;
+                        break;
+                    }
+                }
+            }
+        }
+
+        // Result
+        result.into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day02;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day02::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day02::parse(input.text()).unwrap().part2());
+    }
+}
@@ -0,0 +1,149 @@
+use std::ops::{Add, Div, Sub};
+
+use utils::map::Map;
+use utils::parse::{parse_token, ParseError};
+use utils::point::MapPoint;
+use utils::solution::{Answer, Solution};
+use utils::utils::manhatten_distance;
+
+pub struct Day03 {
+    number: i64,
+}
+
+fn add_neighbor_sum(point_map: &mut Map<i64>, current_point: MapPoint) -> i64 {
+    let neigbors = current_point.generate_neigbors();
+
+    let value: i64 = neigbors
+        .iter()
+        .map(|n| point_map.get_from_point(n).unwrap_or(&0))
+        .sum();
+
+    point_map.set_at_point(&current_point, value);
+    value
+}
+
+impl Solution for Day03 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let number = parse_token(input, input.trim())?;
+        if number < 1 {
+            return Err(ParseError::new(
+                input,
+                input.trim(),
+                "expected a positive number, found",
+            ));
+        }
+
+        Ok(Self { number })
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let result;
+        let mut dimension: i64 = 1;
+        let number = self.number;
+
+        // Prepare
+        while dimension.pow(2) < number {
+            dimension += 2;
+        }
+
+        // Solve
+        let start: i64 = dimension.sub(2).pow(2).add(1);
+        let div_to_start: i64 = number - start;
+
+        if div_to_start < (dimension - 1) {
+            let position = div_to_start.sub(dimension.div(2)).add(1);
+            result = manhatten_distance(0, 0, position, dimension.div(2))
+        } else if div_to_start < ((dimension - 1) * 2) {
+            let position = div_to_start.sub(dimension - 1).sub(dimension.div(2)).add(1);
+            result = manhatten_distance(0, 0, position, dimension.div(2))
+        } else if div_to_start < ((dimension - 1) * 3) {
+            let position = div_to_start
+                .sub((dimension - 1) * 2)
+                .sub(dimension.div(2))
+                .add(1);
+            result = manhatten_distance(0, 0, position, dimension.div(2))
+        } else if div_to_start < ((dimension - 1) * 4) {
+            let position = div_to_start
+                .sub((dimension - 1) * 3)
+                .sub(dimension.div(2))
+                .add(1);
+            result = manhatten_distance(0, 0, position, dimension.div(2))
+        } else {
+            panic!("Something is wrong number: {number} - div_to_start: {div_to_start} - dimension: {dimension}");
+        }
+
+        // Result
+        result.into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Preamble
+        let mut point_map: Map<i64> = Map::with_bounds(-1, 2, -1, 2, 0);
+        let mut dimension: i64 = 1;
+        let number = self.number;
+
+        let mut current_point = MapPoint { x: 0, y: 0 };
+
+        point_map.set_at_point(&current_point, 1);
+
+        // Solve
+        'outer: loop {
+            current_point.move_right_down();
+            dimension += 2;
+
+            let to_walk = dimension - 1;
+
+            for _ in 0..to_walk {
+                current_point.move_up();
+                if add_neighbor_sum(&mut point_map, current_point) > number {
+                    break 'outer;
+                }
+            }
+
+            for _ in 0..to_walk {
+                current_point.move_left();
+                if add_neighbor_sum(&mut point_map, current_point) > number {
+                    break 'outer;
+                }
+            }
+
+            for _ in 0..to_walk {
+                current_point.move_down();
+                if add_neighbor_sum(&mut point_map, current_point) > number {
+                    break 'outer;
+                }
+            }
+
+            for _ in 0..to_walk {
+                current_point.move_right();
+                if add_neighbor_sum(&mut point_map, current_point) > number {
+                    break 'outer;
+                }
+            }
+        }
+
+        // Result
+        (*point_map.get_from_point(&current_point).unwrap()).into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day03;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day03::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day03::parse(input.text()).unwrap().part2());
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::HashSet;
+
+use utils::parse::ParseError;
+use utils::solution::{Answer, Solution};
+
+pub struct Day04 {
+    lines: Vec<String>,
+}
+
+fn has_anagram(line: &str) -> bool {
+    let mut char_counter_set = HashSet::with_capacity(12);
+
+    for word in line.split(' ') {
+        let char_counter = CharCounter::new(word);
+        if !char_counter_set.insert(char_counter) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn has_duplicate_words(line: &str) -> bool {
+    let mut word_set = HashSet::with_capacity(12);
+
+    for word in line.split(' ') {
+        if !word_set.insert(word) {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[derive(Default, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct CharCounter {
+    a: usize,
+    b: usize,
+    c: usize,
+    d: usize,
+    e: usize,
+    f: usize,
+    g: usize,
+    h: usize,
+    i: usize,
+    j: usize,
+    k: usize,
+    l: usize,
+    m: usize,
+    n: usize,
+    o: usize,
+    p: usize,
+    q: usize,
+    r: usize,
+    s: usize,
+    t: usize,
+    u: usize,
+    v: usize,
+    w: usize,
+    x: usize,
+    y: usize,
+    z: usize,
+}
+impl CharCounter {
+    fn new(word: &str) -> Self {
+        let mut rtn = Self::default();
+
+        for c in word.chars() {
+            rtn.add_char(c);
+        }
+
+        rtn
+    }
+
+    fn add_char(&mut self, c: char) {
+        match c {
+            'a' => self.a += 1,
+            'b' => self.b += 1,
+            'c' => self.c += 1,
+            'd' => self.d += 1,
+            'e' => self.e += 1,
+            'f' => self.f += 1,
+            'g' => self.g += 1,
+            'h' => self.h += 1,
+            'i' => self.i += 1,
+            'j' => self.j += 1,
+            'k' => self.k += 1,
+            'l' => self.l += 1,
+            'm' => self.m += 1,
+            'n' => self.n += 1,
+            'o' => self.o += 1,
+            'p' => self.p += 1,
+            'q' => self.q += 1,
+            'r' => self.r += 1,
+            's' => self.s += 1,
+            't' => self.t += 1,
+            'u' => self.u += 1,
+            'v' => self.v += 1,
+            'w' => self.w += 1,
+            'x' => self.x += 1,
+            'y' => self.y += 1,
+            'z' => self.z += 1,
+            _ => panic!("Should not be here with {c}"),
+        };
+    }
+}
+
+impl Solution for Day04 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Vec::new();
+
+        for line in input.lines() {
+            let line = line.trim();
+            if let Some((i, c)) = line
+                .char_indices()
+                .find(|(_, c)| *c != ' ' && !c.is_ascii_lowercase())
+            {
+                return Err(ParseError::new(
+                    input,
+                    &line[i..i + c.len_utf8()],
+                    "expected a lowercase letter, found",
+                ));
+            }
+            lines.push(line.to_string());
+        }
+
+        Ok(Self { lines })
+    }
+
+    fn part1(&self) -> Answer {
+        // Solve
+        let result = self
+            .lines
+            .iter()
+            .filter(|line| !has_duplicate_words(line))
+            .count();
+
+        // Result
+        result.into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Solve
+        let result = self
+            .lines
+            .iter()
+            .filter(|line| !has_anagram(line))
+            .count();
+
+        // Result
+        result.into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day04;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day04::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day04::parse(input.text()).unwrap().part2());
+    }
+}
@@ -0,0 +1,75 @@
+use utils::input::Input;
+use utils::parse::ParseError;
+use utils::solution::{Answer, Solution};
+
+pub struct Day05 {
+    jumps: Vec<i64>,
+}
+
+impl Solution for Day05 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            jumps: Input::from(input).ints()?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let mut result: i64 = 0;
+        let mut current_position: i64 = 0;
+        let mut jumps = self.jumps.clone();
+
+        // Solve
+        while current_position >= 0 && (current_position as usize) < jumps.len() {
+            result += 1;
+            let old = jumps[current_position as usize];
+            jumps[current_position as usize] = old + 1;
+            current_position += old;
+        }
+
+        // Result
+        result.into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Preamble
+        let mut result: i64 = 0;
+        let mut current_position: i64 = 0;
+        let mut jumps = self.jumps.clone();
+
+        // Solve
+        while current_position >= 0 && (current_position as usize) < jumps.len() {
+            result += 1;
+            let old = jumps[current_position as usize];
+            if old < 3 {
+                jumps[current_position as usize] = old + 1;
+            } else {
+                jumps[current_position as usize] = old - 1;
+            }
+            current_position += old;
+        }
+
+        // Result
+        result.into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day05;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day05::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day05::parse(input.text()).unwrap().part2());
+    }
+}
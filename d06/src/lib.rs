@@ -0,0 +1,87 @@
+use utils::cycle::{find_cycle, Cycle};
+use utils::input::Input;
+use utils::parse::ParseError;
+use utils::solution::{Answer, Solution};
+
+pub struct Day06 {
+    banks: Vec<usize>,
+}
+
+fn reallocate(banks: &mut [usize]) {
+    let mut max = *banks.iter().max().unwrap();
+    let mut reallocate = false;
+    let mut current_position = 0;
+    let go_back_value = banks.len() - 1;
+
+    while max != 0 {
+        if reallocate {
+            banks[current_position] += 1;
+            max -= 1;
+        } else if banks[current_position] == max {
+            reallocate = true;
+            banks[current_position] = 0;
+        }
+        current_position = if current_position == go_back_value {
+            0
+        } else {
+            current_position + 1
+        };
+    }
+}
+
+impl Day06 {
+    fn find_cycle(&self) -> Cycle {
+        find_cycle(self.banks.clone(), |banks| {
+            let mut next = banks.clone();
+            reallocate(&mut next);
+            next
+        })
+    }
+}
+
+impl Solution for Day06 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let banks: Vec<usize> = Input::from(input).ints()?;
+        if banks.is_empty() {
+            return Err(ParseError::at_end(input, "expected a memory bank"));
+        }
+
+        Ok(Self { banks })
+    }
+
+    fn part1(&self) -> Answer {
+        // Solve
+        let cycle = self.find_cycle();
+
+        // Result
+        (cycle.mu + cycle.lambda).into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Solve
+        let cycle = self.find_cycle();
+
+        // Result
+        cycle.lambda.into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day06;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day06::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day06::parse(input.text()).unwrap().part2());
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use utils::parse::{next_token, parse_lines, parse_token, ParseError};
+use utils::solution::{Answer, Solution};
+
+#[derive(Debug)]
+#[allow(dead_code)]
+struct Disc {
+    name: String,
+    number: usize,
+    connections: Vec<String>,
+}
+
+impl FromStr for Disc {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split([' ', ',', '(', ')']).filter(|f| !f.is_empty());
+        let name = next_token(s, &mut split, "a name")?.to_string();
+        let number = parse_token(s, next_token(s, &mut split, "a weight")?)?;
+        let connections = match split.next() {
+            None => Vec::new(),
+            Some("->") => split.map(|connection| connection.to_string()).collect(),
+            Some(token) => return Err(ParseError::new(s, token, "expected ->, found")),
+        };
+
+        Ok(Self {
+            name,
+            number,
+            connections,
+        })
+    }
+}
+
+pub struct Day07 {
+    disc_map: HashMap<String, Disc>,
+    root: String,
+}
+
+fn calculate_tower(
+    connection: &str,
+    disc_map: &HashMap<String, Disc>,
+    corrected: &mut Option<usize>,
+) -> usize {
+    let disc = disc_map.get(connection).unwrap();
+    let mut result = disc.number;
+    let mut results = Vec::new();
+
+    if disc.connections.is_empty() {
+        return disc.number;
+    }
+
+    for connection in disc.connections.iter() {
+        let part_result = calculate_tower(connection, disc_map, corrected);
+        result += part_result;
+        results.push(part_result);
+    }
+
+    let min = results.iter().min().unwrap();
+    let max = results.iter().max().unwrap();
+
+    if min == max {
+        return result;
+    }
+
+    let diff = max - min;
+
+    let idx = results.iter().position(|i| i == max).unwrap();
+    let unbalanced_stack = disc.connections.get(idx).unwrap();
+    let disc = disc_map.get(unbalanced_stack).unwrap();
+
+    // The deepest unbalanced disc is found first, every level above is
+    // balanced again by returning the corrected weight.
+    corrected.get_or_insert(disc.number - diff);
+    result - diff
+}
+
+impl Solution for Day07 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        // Preamble
+        let mut names: HashSet<String> = HashSet::new();
+        let mut disc_map: HashMap<String, Disc> = HashMap::new();
+
+        // Parse
+        for disc in parse_lines::<Disc>(input)? {
+            names.insert(disc.name.clone());
+            disc_map.insert(disc.name.clone(), disc);
+        }
+
+        // Prepare
+        for disc in disc_map.values() {
+            for connection in disc.connections.iter() {
+                if !disc_map.contains_key(connection) {
+                    return Err(ParseError::new(input, connection, "unknown disc"));
+                }
+                names.remove(connection);
+            }
+        }
+
+        let root = names
+            .into_iter()
+            .next()
+            .ok_or_else(|| ParseError::at_end(input, "expected a disc no other disc holds"))?;
+
+        Ok(Self { disc_map, root })
+    }
+
+    fn part1(&self) -> Answer {
+        self.root.as_str().into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Solve
+        let mut corrected = None;
+        calculate_tower(&self.root, &self.disc_map, &mut corrected);
+
+        // Result
+        match corrected {
+            Some(result) => result.into(),
+            None => Answer::Empty,
+        }
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day07;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day07::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day07::parse(input.text()).unwrap().part2());
+    }
+}
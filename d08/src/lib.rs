@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use utils::parse::{next_token, parse_lines, parse_token, parse_within, ParseError};
+use utils::solution::{Answer, Solution};
+
+#[derive(Debug)]
+enum Operation {
+    Inc,
+    Dec,
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "inc" => Ok(Operation::Inc),
+            "dec" => Ok(Operation::Dec),
+            _ => Err(ParseError::new(s, s, "expected inc or dec, found")),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Condition {
+    Geater,
+    Lesser,
+    GreaterEquils,
+    LesserEquils,
+    Equils,
+    EquilsNot,
+}
+
+impl FromStr for Condition {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "<" => Ok(Condition::Lesser),
+            ">" => Ok(Condition::Geater),
+            "<=" => Ok(Condition::LesserEquils),
+            ">=" => Ok(Condition::GreaterEquils),
+            "==" => Ok(Condition::Equils),
+            "!=" => Ok(Condition::EquilsNot),
+            _ => Err(ParseError::new(s, s, "expected a comparison, found")),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Instruction {
+    register: String,
+    operation: Operation,
+    operation_value: i64,
+    condition_register: String,
+    condition: Condition,
+    condition_value: i64,
+}
+impl Instruction {
+    fn execute(&self, register_map: &mut HashMap<String, i64>) {
+        if self.check_condition(register_map) {
+            match self.operation {
+                Operation::Inc => {
+                    *register_map.get_mut(&self.register).unwrap() += self.operation_value;
+                }
+                Operation::Dec => {
+                    *register_map.get_mut(&self.register).unwrap() -= self.operation_value;
+                }
+            }
+        }
+    }
+
+    fn check_condition(&self, register_map: &mut HashMap<String, i64>) -> bool {
+        // A register that is never written to keeps its initial value.
+        let conditional_variable_value = register_map
+            .get(&self.condition_register)
+            .copied()
+            .unwrap_or(0);
+
+        match self.condition {
+            Condition::Geater => conditional_variable_value > self.condition_value,
+            Condition::Lesser => conditional_variable_value < self.condition_value,
+            Condition::GreaterEquils => conditional_variable_value >= self.condition_value,
+            Condition::LesserEquils => conditional_variable_value <= self.condition_value,
+            Condition::Equils => conditional_variable_value == self.condition_value,
+            Condition::EquilsNot => conditional_variable_value != self.condition_value,
+        }
+    }
+
+    fn execute_with_callback(&self, register_map: &mut HashMap<String, i64>, callback: impl FnOnce(i64)) {
+        if self.check_condition(register_map) {
+            match self.operation {
+                Operation::Inc => {
+                    *register_map.get_mut(&self.register).unwrap() += self.operation_value;
+                }
+                Operation::Dec => {
+                    *register_map.get_mut(&self.register).unwrap() -= self.operation_value;
+                }
+            };
+            callback(*register_map.get(&self.register).unwrap());
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split_whitespace();
+        let register = next_token(s, &mut split, "a register")?.to_string();
+        let operation = parse_within(s, next_token(s, &mut split, "inc or dec")?)?;
+        let operation_value = parse_token(s, next_token(s, &mut split, "a number")?)?;
+        let keyword = next_token(s, &mut split, "if")?;
+        if keyword != "if" {
+            return Err(ParseError::new(s, keyword, "expected if, found"));
+        }
+        let condition_register = next_token(s, &mut split, "a register")?.to_string();
+        let condition = parse_within(s, next_token(s, &mut split, "a comparison")?)?;
+        let condition_value = parse_token(s, next_token(s, &mut split, "a number")?)?;
+        if let Some(token) = split.next() {
+            return Err(ParseError::new(s, token, "unexpected"));
+        }
+
+        Ok(Self {
+            register,
+            operation,
+            operation_value,
+            condition_register,
+            condition,
+            condition_value,
+        })
+    }
+}
+
+pub struct Day08 {
+    instructions: Vec<Instruction>,
+}
+
+impl Day08 {
+    fn create_register_map(&self) -> HashMap<String, i64> {
+        let mut register_map: HashMap<String, i64> = HashMap::new();
+        for instruction in self.instructions.iter() {
+            register_map.entry(instruction.register.clone()).or_insert(0);
+        }
+        register_map
+    }
+}
+
+impl Solution for Day08 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let instructions: Vec<Instruction> = parse_lines(input)?;
+        if instructions.is_empty() {
+            return Err(ParseError::at_end(input, "expected an instruction"));
+        }
+
+        Ok(Self { instructions })
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let mut register_map = self.create_register_map();
+
+        // Solve
+        for instruction in self.instructions.iter() {
+            instruction.execute(&mut register_map);
+        }
+
+        // Result
+        (*register_map.values().max().unwrap()).into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Preamble
+        let mut register_map = self.create_register_map();
+
+        // Solve
+        let mut result = i64::MIN;
+        for instruction in self.instructions.iter() {
+            instruction.execute_with_callback(&mut register_map, |value: i64| {
+                result = result.max(value);
+            });
+        }
+
+        // Result
+        result.into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day08;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day08::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day08::parse(input.text()).unwrap().part2());
+    }
+}
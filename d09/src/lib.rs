@@ -0,0 +1,178 @@
+use utils::parse::ParseError;
+use utils::solution::{Answer, Solution};
+
+#[derive(PartialEq, Eq)]
+enum ParserState {
+    Start,
+    Group,
+    Garbage,
+}
+
+impl ParserState {
+    pub fn looking_for(&self) -> Vec<char> {
+        match self {
+            ParserState::Group => vec!['{', '}', '<'],
+            ParserState::Garbage => vec!['!', '>'],
+            ParserState::Start => vec!['{'],
+        }
+    }
+}
+
+struct ParserContext {
+    state: ParserState,
+    next_score: usize,
+}
+
+enum ParserAction {
+    GroupOpen,
+    GroupClose,
+    GarbageOpen,
+    GarbageClose,
+    Esquape,
+    Skip,
+}
+
+impl ParserAction {
+    fn to_action(value: char) -> Self {
+        match value {
+            '{' => ParserAction::GroupOpen,
+            '}' => ParserAction::GroupClose,
+            '<' => ParserAction::GarbageOpen,
+            '>' => ParserAction::GarbageClose,
+            '!' => ParserAction::Esquape,
+            _ => ParserAction::Skip,
+        }
+    }
+}
+
+fn calculate_score(line: &str) -> usize {
+    let mut score = 0;
+    let mut context: ParserContext = ParserContext {
+        state: ParserState::Start,
+        next_score: 0,
+    };
+
+    let mut skip = false;
+
+    for c in line.chars() {
+        if skip {
+            skip = false;
+            continue;
+        }
+
+        if !context.state.looking_for().contains(&c) {
+            continue;
+        }
+
+        let action: ParserAction = ParserAction::to_action(c);
+
+        match action {
+            ParserAction::GroupOpen => {
+                context.state = ParserState::Group;
+                context.next_score += 1;
+            }
+            ParserAction::GroupClose => {
+                score += context.next_score;
+                context.next_score -= 1;
+                context.state = ParserState::Group;
+            }
+            ParserAction::GarbageOpen => {
+                context.state = ParserState::Garbage;
+            }
+            ParserAction::GarbageClose => {
+                context.state = ParserState::Group;
+            }
+            ParserAction::Esquape => {
+                skip = true;
+            }
+            ParserAction::Skip => {}
+        };
+    }
+    score
+}
+
+fn calculate_score2(line: &str) -> usize {
+    let mut score = 0;
+    let mut context: ParserContext = ParserContext {
+        state: ParserState::Start,
+        next_score: 0,
+    };
+
+    let mut skip = false;
+
+    for c in line.chars() {
+        if skip {
+            skip = false;
+            continue;
+        }
+
+        if !context.state.looking_for().contains(&c) {
+            if context.state == ParserState::Garbage {
+                score += 1;
+            }
+            continue;
+        }
+
+        let action: ParserAction = ParserAction::to_action(c);
+
+        match action {
+            ParserAction::GroupOpen => {
+                context.state = ParserState::Group;
+            }
+            ParserAction::GroupClose => {
+                context.state = ParserState::Group;
+            }
+            ParserAction::GarbageOpen => {
+                context.state = ParserState::Garbage;
+            }
+            ParserAction::GarbageClose => {
+                context.state = ParserState::Group;
+            }
+            ParserAction::Esquape => {
+                skip = true;
+            }
+            ParserAction::Skip => {}
+        };
+    }
+    score
+}
+
+pub struct Day09 {
+    line: String,
+}
+
+impl Solution for Day09 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            line: input.trim().to_string(),
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        calculate_score(&self.line).into()
+    }
+
+    fn part2(&self) -> Answer {
+        calculate_score2(&self.line).into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day09;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day09::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day09::parse(input.text()).unwrap().part2());
+    }
+}
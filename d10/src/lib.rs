@@ -0,0 +1,166 @@
+use utils::params;
+use utils::params::Params;
+use utils::parse::{parse_token, ParseError};
+use utils::solution::{Answer, Solution};
+
+params! {
+    pub struct Day10Params {
+        /// Length of the list of part 1, part 2 always hashes 256 numbers.
+        pub list_size: usize = 256,
+    }
+}
+
+fn reverse(current_position: usize, length: u8, list: &mut [u8]) {
+    if length == 0 {
+        return;
+    }
+
+    let end = (current_position + length as usize - 1) % list.len();
+    let swaps = length as usize / 2;
+
+    for i in 0..swaps {
+        let start: usize = (i + current_position) % list.len();
+        let end: usize = (list.len() + end - i) % list.len();
+        list.swap(start, end);
+    }
+}
+
+fn xor_list(list: &[u8]) -> u8 {
+    let mut rtn: u8 = 0;
+    for i in list {
+        rtn ^= i;
+    }
+    rtn
+}
+
+fn dense_hashlist(list: &[u8]) -> [u8; 16] {
+    let mut rtn = [0; 16];
+
+    for (i, item) in rtn.iter_mut().enumerate() {
+        let slice_start = i * 16;
+        let slice_end = slice_start + 16;
+        *item = xor_list(&list[slice_start..slice_end]);
+    }
+
+    rtn
+}
+
+pub struct Day10 {
+    line: String,
+    lengths: Vec<u8>,
+    params: Day10Params,
+}
+
+impl Solution for Day10 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let line = input.trim();
+        // Part 2 hashes any line, even an empty one. A length fits into a u8
+        // and so into the default list of 256 numbers.
+        let lengths = if line.is_empty() {
+            Vec::new()
+        } else {
+            line.split(',')
+                .map(|length| parse_token(input, length.trim()))
+                .collect::<Result<_, _>>()?
+        };
+
+        Ok(Self {
+            line: line.to_string(),
+            lengths,
+            params: Day10Params::default(),
+        })
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let mut params = self.params.clone();
+        params.set(name, value)?;
+        // The list holds bytes and part 1 multiplies its first two numbers.
+        if !(2..=256).contains(&params.list_size) {
+            return Err(format!(
+                "Invalid value {value:?} for parameter {name}: expected 2 to 256"
+            ));
+        }
+        if let Some(length) = self
+            .lengths
+            .iter()
+            .find(|length| **length as usize > params.list_size)
+        {
+            return Err(format!(
+                "Invalid value {value:?} for parameter {name}: the length {length} does not fit the list"
+            ));
+        }
+        self.params = params;
+        Ok(())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        self.params.values()
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let mut current_position = 0;
+        let mut list: Vec<u8> = (0..self.params.list_size).map(|i| i as u8).collect();
+
+        // Solve
+        for (skip_lenght, length) in self.lengths.iter().copied().enumerate() {
+            reverse(current_position, length, &mut list);
+            current_position = (current_position + skip_lenght + length as usize) % list.len();
+        }
+
+        // Result
+        let result: usize = list[0] as usize * list[1] as usize;
+        result.into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Preamble
+        let mut current_position: usize = 0;
+        let mut skip_lenght: usize = 0;
+
+        const RAW: [u8; 5] = [17, 31, 73, 47, 23];
+        let mut list: Vec<u8> = Vec::from_iter(0..=255);
+
+        // Parse
+        let mut lengths = Vec::from(self.line.as_bytes());
+        lengths.extend(&RAW);
+
+        for _ in 0..64 {
+            for length in lengths.iter() {
+                reverse(current_position, *length, &mut list);
+                current_position = (current_position + skip_lenght + *length as usize) % list.len();
+                skip_lenght += 1;
+            }
+        }
+
+        // Result
+        let result = dense_hashlist(&list);
+        result
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect::<String>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::{Day10, Day10Params};
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        let mut day = Day10::parse(input.text()).unwrap();
+        day.params = Day10Params { list_size: 5 };
+        println!("{}", day.part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test_2(file!()).unwrap();
+        println!("{}", Day10::parse(input.text()).unwrap().part2());
+    }
+}
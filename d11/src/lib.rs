@@ -0,0 +1,63 @@
+use utils::hex::{parse_steps, Cube, FlatDirection};
+use utils::parse::ParseError;
+use utils::solution::{Answer, Solution};
+
+pub struct Day11 {
+    directions: Vec<FlatDirection>,
+}
+
+impl Solution for Day11 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            directions: parse_steps(input)?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let mut position = Cube::default();
+
+        // Solve
+        for direction in self.directions.iter() {
+            position = position.neighbor(*direction);
+        }
+
+        // Result
+        position.length().into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Preamble
+        let mut position = Cube::default();
+        let mut max_distance: i64 = 0;
+
+        // Solve
+        for direction in self.directions.iter() {
+            position = position.neighbor(*direction);
+            max_distance = max_distance.max(position.length());
+        }
+
+        // Result
+        max_distance.into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day11;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day11::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day11::parse(input.text()).unwrap().part2());
+    }
+}
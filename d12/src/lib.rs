@@ -0,0 +1,62 @@
+use utils::graph::Graph;
+use utils::parse::ParseError;
+use utils::solution::{Answer, Solution};
+use utils::union_find::UnionFind;
+
+pub struct Day12 {
+    graph: Graph<u32>,
+}
+
+impl Solution for Day12 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            graph: Graph::parse_adjacency(input, "<->")?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        // Solve
+        let result = match self.graph.id(&0) {
+            Some(id) => self.graph.component_of(id).len(),
+            None => 0,
+        };
+
+        // Result
+        result.into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Preamble
+        let mut groups = UnionFind::new(self.graph.len());
+
+        // Solve
+        for id in 0..self.graph.len() {
+            for neighbor in self.graph.neighbors(id) {
+                groups.union(id, *neighbor);
+            }
+        }
+
+        // Result
+        groups.set_count().into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day12;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day12::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day12::parse(input.text()).unwrap().part2());
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use utils::parse::{parse_lines, parse_token, ParseError};
+use utils::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy)]
+struct Scanner {
+    pub current_postion: usize,
+    dir_change: usize,
+    pub depth: usize,
+    goes_down: bool,
+}
+
+fn walk_all(scanner_map: &mut HashMap<usize, Scanner>) {
+    scanner_map.iter_mut().for_each(|s| s.1.walk())
+}
+
+// fn reset_all(scanner_map: &mut HashMap<usize, Scanner>) {
+//     scanner_map.iter_mut().for_each(|s| s.1.reset())
+// }
+
+fn calculate_hit_state(scanner_map: &HashMap<usize, Scanner>) -> HashSet<usize> {
+    scanner_map
+        .iter()
+        .filter(|s| s.1.current_postion == 0)
+        .map(|s| *s.0)
+        .collect()
+}
+
+fn get_current_hit_state<'a>(
+    delay: usize,
+    cache: &'a mut Vec<HashSet<usize>>,
+    scanner_map: &'a mut HashMap<usize, Scanner>,
+) -> &'a HashSet<usize> {
+    while cache.len() <= delay {
+        let hit_state = calculate_hit_state(scanner_map);
+        cache.push(hit_state);
+        walk_all(scanner_map);
+    }
+    cache.get(delay).unwrap()
+}
+
+impl Scanner {
+    fn new(depth: usize) -> Self {
+        Self {
+            current_postion: 0,
+            dir_change: depth - 1,
+            depth,
+            goes_down: true,
+        }
+    }
+
+    // fn reset(&mut self) {
+    //     self.current_postion = 0;
+    //     self.goes_down = true;
+    // }
+
+    fn walk(&mut self) {
+        if self.goes_down {
+            self.current_postion += 1;
+            if self.dir_change == self.current_postion {
+                self.goes_down = false;
+            }
+        } else {
+            self.current_postion -= 1;
+            if self.current_postion == 0 {
+                self.goes_down = true;
+            }
+        }
+    }
+}
+
+struct Layer {
+    layer: usize,
+    depth: usize,
+}
+
+impl FromStr for Layer {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (layer_token, depth_token) = s.split_once(':').ok_or_else(|| {
+            ParseError::new(s, s, "expected a layer and a depth separated by :, found")
+        })?;
+        let layer = parse_token(s, layer_token.trim())?;
+        let depth = parse_token(s, depth_token.trim())?;
+        if depth == 0 {
+            return Err(ParseError::new(
+                s,
+                depth_token.trim(),
+                "expected a depth of at least 1, found",
+            ));
+        }
+
+        Ok(Self { layer, depth })
+    }
+}
+
+pub struct Day13 {
+    scanner_map: HashMap<usize, Scanner>,
+    num_layer: usize,
+}
+
+impl Solution for Day13 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut scanner_map = HashMap::new();
+        let mut num_layer = 0;
+
+        for Layer { layer, depth } in parse_lines(input)? {
+            num_layer = num_layer.max(layer);
+            let scanner = Scanner::new(depth);
+            scanner_map.insert(layer, scanner);
+        }
+
+        Ok(Self {
+            scanner_map,
+            num_layer,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let mut scanner_map = self.scanner_map.clone();
+
+        // Prepare
+        let mut hits: Vec<usize> = Vec::new();
+
+        // Solve
+        for current_position in 0..=self.num_layer {
+            if let Some(scanner) = scanner_map.get(&current_position) {
+                if scanner.current_postion == 0 {
+                    hits.push(current_position);
+                }
+            }
+            walk_all(&mut scanner_map);
+        }
+
+        // Result
+        let mut result: usize = 0;
+
+        for hit in hits {
+            let scanner = scanner_map.get(&hit).unwrap();
+            result += scanner.depth * hit;
+        }
+
+        result.into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Preamble
+        let mut scanner_map = self.scanner_map.clone();
+        let mut delay: usize = 0;
+
+        // Prepare
+        let mut cache: Vec<HashSet<usize>> = Vec::new();
+
+        // Solve
+        'outer: loop {
+            for current_position in 0..=self.num_layer {
+                let current_time = delay + current_position;
+                let hit_state: &HashSet<usize> =
+                    get_current_hit_state(current_time, &mut cache, &mut scanner_map);
+
+                if hit_state.contains(&current_position) {
+                    delay += 1;
+                    continue 'outer;
+                }
+            }
+            break 'outer;
+        }
+
+        // Result
+        delay.into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day13;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day13::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day13::parse(input.text()).unwrap().part2());
+    }
+}
@@ -0,0 +1,167 @@
+use utils::parse::ParseError;
+use utils::solution::{Answer, Solution};
+use utils::union_find::UnionFind;
+
+// fn print_grid(grid: &[u128]) {
+//     for row in grid {
+//         println!("{:0128b}", row);
+//     }
+// }
+
+fn is_hit(x: i64, y: i64, grid: &[u128]) -> bool {
+    (grid[y as usize] & 1 << (127 - x)) != 0
+}
+
+fn create_grid(line: &str) -> Vec<u128> {
+    let mut grid: Vec<u128> = Vec::with_capacity(128);
+
+    for i in 0..128 {
+        let to_hash_string = format!("{}-{}", line, i);
+        let hash = create_hash(&to_hash_string);
+        let mut to_grid: u128 = 0;
+
+        to_grid += hash[15] as u128;
+        to_grid += (hash[14] as u128) << 8;
+        to_grid += (hash[13] as u128) << 16;
+        to_grid += (hash[12] as u128) << 24;
+        to_grid += (hash[11] as u128) << 32;
+        to_grid += (hash[10] as u128) << 40;
+        to_grid += (hash[9] as u128) << 48;
+        to_grid += (hash[8] as u128) << 56;
+        to_grid += (hash[7] as u128) << 64;
+        to_grid += (hash[6] as u128) << 72;
+        to_grid += (hash[5] as u128) << 80;
+        to_grid += (hash[4] as u128) << 88;
+        to_grid += (hash[3] as u128) << 96;
+        to_grid += (hash[2] as u128) << 104;
+        to_grid += (hash[1] as u128) << 112;
+        to_grid += (hash[0] as u128) << 120;
+
+        grid.push(to_grid);
+    }
+
+    grid
+}
+
+fn create_hash(to_hash_string: &str) -> [u8; 16] {
+    const RAW: [u8; 5] = [17, 31, 73, 47, 23];
+    let mut current_position = 0;
+    let mut skip_lenght = 0;
+    let mut list: Vec<u8> = Vec::from_iter(0..=255);
+    let mut length = Vec::from_iter(to_hash_string.bytes());
+    length.extend(RAW);
+    for _ in 0..64 {
+        for byte in length.iter() {
+            reverse(current_position, *byte, &mut list);
+            current_position =
+                (current_position + skip_lenght + *byte as usize) % list.len();
+            skip_lenght += 1;
+        }
+    }
+
+    dense_hashlist(&list)
+}
+
+fn reverse(current_position: usize, length: u8, list: &mut [u8]) {
+    let end = (current_position + length as usize - 1) % list.len();
+    let swaps = length as usize / 2;
+
+    for i in 0..swaps {
+        let start: usize = (i + current_position) % list.len();
+        let end: usize = (list.len() + end - i) % list.len();
+        list.swap(start, end);
+    }
+}
+
+fn xor_list(list: &[u8]) -> u8 {
+    // println!("{list:?}");
+    let mut rtn: u8 = 0;
+    for i in list {
+        rtn ^= i;
+    }
+    rtn
+}
+
+fn dense_hashlist(list: &[u8]) -> [u8; 16] {
+    let mut rtn = [0; 16];
+
+    for (i, item) in rtn.iter_mut().enumerate() {
+        let slice_start = i * 16;
+        let slice_end = slice_start + 16;
+        *item = xor_list(&list[slice_start..slice_end]);
+    }
+
+    rtn
+}
+
+pub struct Day14 {
+    line: String,
+}
+
+impl Solution for Day14 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            line: input.trim().to_string(),
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        // Solve
+        let grid: Vec<u128> = create_grid(&self.line);
+
+        // print_grid(&grid);
+        // Result
+        let result: u32 = grid.iter().map(|i| i.count_ones()).sum();
+        result.into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Preamble
+        let mut regions = UnionFind::new(128 * 128);
+
+        // Prepare
+        let grid: Vec<u128> = create_grid(&self.line);
+        let used: u32 = grid.iter().map(|i| i.count_ones()).sum();
+
+        // Solve
+        for y in 0..128 {
+            for x in 0..128 {
+                if !is_hit(x, y, &grid) {
+                    continue;
+                }
+
+                let id = (y * 128 + x) as usize;
+                if x < 127 && is_hit(x + 1, y, &grid) {
+                    regions.union(id, id + 1);
+                }
+                if y < 127 && is_hit(x, y + 1, &grid) {
+                    regions.union(id, id + 128);
+                }
+            }
+        }
+
+        // Result
+        // Every free square is a set of its own.
+        (regions.set_count() - (128 * 128 - used as usize)).into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day14;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day14::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day14::parse(input.text()).unwrap().part2());
+    }
+}
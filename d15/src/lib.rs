@@ -0,0 +1,153 @@
+use std::str::FromStr;
+
+use utils::params;
+use utils::params::Params;
+use utils::parse::{next_token, parse_lines, parse_token, ParseError};
+use utils::solution::{Answer, Solution};
+
+params! {
+    pub struct Day15Params {
+        pub part1_pairs: usize = 40_000_000,
+        pub part2_pairs: usize = 5_000_000,
+    }
+}
+
+const GEN_A_FACTOR: u64 = 16807;
+const GEN_B_FACTOR: u64 = 48271;
+
+const GEN_DIVIDER: u64 = 2147483647;
+
+struct Generator {
+    start: u64,
+}
+
+impl FromStr for Generator {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split_whitespace();
+        let generator = next_token(s, &mut split, "Generator")?;
+        next_token(s, &mut split, "a generator name")?;
+        let starts = next_token(s, &mut split, "starts")?;
+        let with = next_token(s, &mut split, "with")?;
+        for (token, expected) in [(generator, "Generator"), (starts, "starts"), (with, "with")] {
+            if token != expected {
+                return Err(ParseError::new(
+                    s,
+                    token,
+                    format!("expected {expected}, found"),
+                ));
+            }
+        }
+        // Below 2^32 the products with the factors fit into a u64.
+        let start: u32 = parse_token(s, next_token(s, &mut split, "a start value")?)?;
+        if let Some(token) = split.next() {
+            return Err(ParseError::new(s, token, "unexpected"));
+        }
+
+        Ok(Self {
+            start: start.into(),
+        })
+    }
+}
+
+pub struct Day15 {
+    gen_a: u64,
+    gen_b: u64,
+    params: Day15Params,
+}
+
+impl Solution for Day15 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let generators: Vec<Generator> = parse_lines(input)?;
+        let [gen_a, gen_b] = generators.as_slice() else {
+            return Err(ParseError::at_end(
+                input,
+                format!("expected two generators, found {}", generators.len()),
+            ));
+        };
+
+        Ok(Self {
+            gen_a: gen_a.start,
+            gen_b: gen_b.start,
+            params: Day15Params::default(),
+        })
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        self.params.set(name, value)
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        self.params.values()
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let mut gen_a = self.gen_a;
+        let mut gen_b = self.gen_b;
+
+        // Solve
+        let mut pairs: usize = 0;
+
+        for _ in 0..self.params.part1_pairs {
+            gen_a = (GEN_A_FACTOR * gen_a) % GEN_DIVIDER;
+            gen_b = (GEN_B_FACTOR * gen_b) % GEN_DIVIDER;
+
+            if (gen_a & 0xFFFF) == (gen_b & 0xFFFF) {
+                pairs += 1;
+            }
+        }
+
+        // Result
+        pairs.into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Preamble
+        let mut gen_a = self.gen_a;
+        let mut gen_b = self.gen_b;
+
+        // Solve
+        let mut pairs: usize = 0;
+
+        for _ in 0..self.params.part2_pairs {
+            gen_a = (GEN_A_FACTOR * gen_a) % GEN_DIVIDER;
+            while !gen_a.is_multiple_of(4) {
+                gen_a = (GEN_A_FACTOR * gen_a) % GEN_DIVIDER;
+            }
+
+            gen_b = (GEN_B_FACTOR * gen_b) % GEN_DIVIDER;
+            while !gen_b.is_multiple_of(8) {
+                gen_b = (GEN_B_FACTOR * gen_b) % GEN_DIVIDER;
+            }
+
+            if (gen_a & 0xFFFF) == (gen_b & 0xFFFF) {
+                pairs += 1;
+            }
+        }
+
+        // Result
+        pairs.into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day15;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day15::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day15::parse(input.text()).unwrap().part2());
+    }
+}
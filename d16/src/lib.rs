@@ -0,0 +1,234 @@
+use std::str::FromStr;
+
+use utils::cycle::nth_state;
+use utils::params;
+use utils::params::Params;
+use utils::parse::{next_token, parse_token, parse_within, ParseError};
+use utils::solution::{Answer, Solution};
+
+params! {
+    pub struct Day16Params {
+        pub dancers: usize = 16,
+        /// Number of dances of part 2.
+        pub dances: usize = 1_000_000_000,
+    }
+}
+
+const MAX_DANCER: char = 'z';
+
+#[derive(Debug)]
+enum Instruction {
+    Spin { v1: usize },
+    Exchange { v1: usize, v2: usize },
+    Partner { v1: char, v2: char },
+}
+
+fn parse_dancer(source: &str, token: &str) -> Result<char, ParseError> {
+    let dancer = parse_token(source, token)?;
+    if !('a'..=MAX_DANCER).contains(&dancer) {
+        return Err(ParseError::new(
+            source,
+            token,
+            "expected a dancer from a to z, found",
+        ));
+    }
+    Ok(dancer)
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(i_type) = s.chars().next() else {
+            return Err(ParseError::at_end(s, "expected a dance move"));
+        };
+        let value = &s[i_type.len_utf8()..];
+        let mut split = value.split('/');
+
+        let rtn = match i_type {
+            's' => Instruction::Spin {
+                v1: parse_token(s, next_token(s, &mut split, "a size")?)?,
+            },
+            'x' => Instruction::Exchange {
+                v1: parse_token(s, next_token(s, &mut split, "a position")?)?,
+                v2: parse_token(s, next_token(s, &mut split, "a position")?)?,
+            },
+            'p' => Instruction::Partner {
+                v1: parse_dancer(s, next_token(s, &mut split, "a dancer")?)?,
+                v2: parse_dancer(s, next_token(s, &mut split, "a dancer")?)?,
+            },
+            _ => {
+                return Err(ParseError::new(
+                    s,
+                    &s[..i_type.len_utf8()],
+                    "expected s, x or p, found",
+                ))
+            }
+        };
+        if let Some(token) = split.next() {
+            return Err(ParseError::new(s, token, "unexpected"));
+        }
+
+        Ok(rtn)
+    }
+}
+
+impl Instruction {
+    /// The fewest dancers this move can be danced with.
+    fn min_dancers(&self) -> usize {
+        match self {
+            Instruction::Spin { v1 } => *v1,
+            Instruction::Exchange { v1, v2 } => v1.max(v2) + 1,
+            Instruction::Partner { v1, v2 } => (*v1.max(v2) as u8 - b'a') as usize + 1,
+        }
+    }
+
+    pub fn work_on(&self, line: &mut [char]) {
+        match self {
+            Instruction::Spin { v1 } => {
+                line.rotate_right(*v1);
+            }
+            Instruction::Exchange { v1, v2 } => {
+                line.swap(*v1, *v2);
+            }
+            Instruction::Partner { v1, v2 } => {
+                let mut p1 = usize::MAX;
+                let mut p2 = usize::MAX;
+                for (i, char) in line.iter().enumerate() {
+                    if char == v1 {
+                        p1 = i;
+                    }
+                    if char == v2 {
+                        p2 = i;
+                    }
+                    if p1 != usize::MAX && p2 != usize::MAX {
+                        break;
+                    }
+                }
+                line.swap(p1, p2);
+            }
+        }
+    }
+}
+
+pub struct Day16 {
+    instructions: Vec<Instruction>,
+    params: Day16Params,
+}
+
+impl Day16 {
+    fn start_order(&self) -> Vec<char> {
+        (0..self.params.dancers)
+            .map(|i| (b'a' + i as u8) as char)
+            .collect()
+    }
+}
+
+impl Solution for Day16 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let params = Day16Params::default();
+        let mut instructions = Vec::new();
+        for token in input.trim().split(',') {
+            let instruction: Instruction = parse_within(input, token)?;
+            if instruction.min_dancers() > params.dancers {
+                return Err(ParseError::new(
+                    input,
+                    token,
+                    format!("expected a move for {} dancers, found", params.dancers),
+                ));
+            }
+            instructions.push(instruction);
+        }
+
+        Ok(Self {
+            instructions,
+            params,
+        })
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let mut params = self.params.clone();
+        params.set(name, value)?;
+        let min = self
+            .instructions
+            .iter()
+            .map(Instruction::min_dancers)
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let max = (MAX_DANCER as u8 - b'a') as usize + 1;
+        if !(min..=max).contains(&params.dancers) {
+            return Err(format!(
+                "Invalid number of dancers {}: expected {min} to {max}",
+                params.dancers
+            ));
+        }
+        self.params = params;
+        Ok(())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        self.params.values()
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let mut order = self.start_order();
+
+        // Solve
+        for instruction in self.instructions.iter() {
+            instruction.work_on(&mut order);
+        }
+
+        // Result
+        String::from_iter(&order).into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Solve
+        let order = nth_state(
+            self.start_order(),
+            |order| {
+                let mut next = order.clone();
+                for instruction in self.instructions.iter() {
+                    instruction.work_on(&mut next);
+                }
+                next
+            },
+            self.params.dances,
+        );
+
+        // Result
+        String::from_iter(&order).into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::{Day16, Day16Params};
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        let mut day = Day16::parse(input.text()).unwrap();
+        day.params = Day16Params {
+            dancers: 5,
+            dances: 2,
+        };
+        println!("{}", day.part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        let mut day = Day16::parse(input.text()).unwrap();
+        day.params = Day16Params {
+            dancers: 5,
+            dances: 2,
+        };
+        println!("{}", day.part2());
+    }
+}
@@ -0,0 +1,68 @@
+use utils::parse::{parse_token, ParseError};
+use utils::solution::{Answer, Solution};
+
+pub struct Day17 {
+    steps: usize,
+}
+
+impl Solution for Day17 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            steps: parse_token(input, input.trim())?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        // Prepare
+        let mut ring: Vec<usize> = Vec::with_capacity(2018);
+        ring.push(0);
+
+        let mut current_positon = 0;
+
+        // Solve
+        for i in 1..2018 {
+            current_positon = ((current_positon + self.steps) % ring.len()) + 1;
+            ring.insert(current_positon, i);
+        }
+
+        // Result
+        (*ring.get(current_positon + 1).unwrap()).into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Prepare
+        let mut current_positon = 0;
+        let mut result: usize = 60000000;
+
+        // Solve
+        for i in 1..50000000 {
+            current_positon = ((current_positon + self.steps) % i) + 1;
+            if current_positon == 1 {
+                result = i;
+            }
+        }
+
+        // Result
+        result.into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day17;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day17::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day17::parse(input.text()).unwrap().part2());
+    }
+}
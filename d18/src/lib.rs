@@ -0,0 +1,390 @@
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
+
+use utils::parse::{next_token, parse_lines, parse_within, ParseError};
+use utils::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy)]
+enum RegisterValue {
+    Register(char),
+    Value(i64),
+}
+impl RegisterValue {
+    fn eval(&self, registers: &HashMap<char, i64>) -> i64 {
+        match self {
+            RegisterValue::Register(register) => registers.get(register).copied().unwrap_or(0),
+            RegisterValue::Value(value) => *value,
+        }
+    }
+}
+
+impl FromStr for RegisterValue {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(value) = s.parse() {
+            return Ok(RegisterValue::Value(value));
+        }
+
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(register), None) => Ok(RegisterValue::Register(register)),
+            _ => Err(ParseError::new(
+                s,
+                s,
+                "expected a register or a number, found",
+            )),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Snd {
+        register: char,
+    },
+    Set {
+        register: char,
+        value: RegisterValue,
+    },
+    Add {
+        register: char,
+        value: RegisterValue,
+    },
+    Mul {
+        register: char,
+        value: RegisterValue,
+    },
+    Mod {
+        register: char,
+        value: RegisterValue,
+    },
+    Rcv {
+        register: char,
+    },
+    Jgz {
+        value_1: RegisterValue,
+        value_2: RegisterValue,
+    },
+}
+impl Instruction {
+    fn execute(&self, registers: &mut HashMap<char, i64>) -> [i64; 2] {
+        let mut next = 1;
+        let mut rcv = 0;
+        match self {
+            Instruction::Snd { register } => self.snd(register, registers),
+            Instruction::Set { register, value } => self.set(register, value, registers),
+            Instruction::Add { register, value } => self.add(register, value, registers),
+            Instruction::Mul { register, value } => self.mul(register, value, registers),
+            Instruction::Mod { register, value } => self.modular(register, value, registers),
+            Instruction::Rcv { register } => {
+                rcv = self.rcv(register, registers);
+            }
+            Instruction::Jgz { value_1, value_2 } => {
+                next = self.jgz(value_1, value_2, registers);
+            }
+        };
+
+        [next, rcv]
+    }
+
+    fn execute2(
+        &self,
+        registers: &mut HashMap<char, i64>,
+        recv_queue: &mut VecDeque<i64>,
+        send_queue: &mut VecDeque<i64>,
+        is_execution_0: bool,
+    ) -> i64 {
+        let mut next = 1;
+        match self {
+            Instruction::Snd { register } => {
+                self.snd2(register, registers, send_queue, is_execution_0)
+            }
+            Instruction::Set { register, value } => self.set(register, value, registers),
+            Instruction::Add { register, value } => self.add(register, value, registers),
+            Instruction::Mul { register, value } => self.mul(register, value, registers),
+            Instruction::Mod { register, value } => self.modular(register, value, registers),
+            Instruction::Rcv { register } => next = self.rcv2(register, registers, recv_queue),
+            Instruction::Jgz { value_1, value_2 } => next = self.jgz(value_1, value_2, registers),
+        };
+        next
+    }
+
+    fn snd(&self, register: &char, registers: &mut HashMap<char, i64>) {
+        registers.insert('0', registers.get(register).copied().unwrap_or(0));
+    }
+
+    fn rcv(&self, register: &char, registers: &mut HashMap<char, i64>) -> i64 {
+        registers.get(register).copied().unwrap_or(0)
+    }
+
+    fn set(&self, register: &char, value: &RegisterValue, registers: &mut HashMap<char, i64>) {
+        let value = value.eval(registers);
+        registers
+            .entry(*register)
+            .and_modify(|i| *i = value)
+            .or_insert(value);
+    }
+
+    fn add(&self, register: &char, value: &RegisterValue, registers: &mut HashMap<char, i64>) {
+        let value = value.eval(registers);
+        registers
+            .entry(*register)
+            .and_modify(|i| *i = i.checked_add(value).unwrap())
+            .or_insert(value);
+    }
+
+    fn mul(&self, register: &char, value: &RegisterValue, registers: &mut HashMap<char, i64>) {
+        let value = value.eval(registers);
+        registers
+            .entry(*register)
+            .and_modify(|i| *i = i.checked_mul(value).unwrap())
+            .or_insert(0);
+    }
+
+    fn modular(&self, register: &char, value: &RegisterValue, registers: &mut HashMap<char, i64>) {
+        let value = value.eval(registers);
+        registers
+            .entry(*register)
+            .and_modify(|i| *i %= value)
+            .or_insert(0);
+    }
+
+    fn jgz(
+        &self,
+        v1: &RegisterValue,
+        v2: &RegisterValue,
+        registers: &mut HashMap<char, i64>,
+    ) -> i64 {
+        let v1v = v1.eval(registers);
+        let v2v: i64 = v2.eval(registers);
+        if v1v > 0 {
+            v2v
+        } else {
+            1
+        }
+    }
+
+    fn snd2(
+        &self,
+        register: &char,
+        registers: &mut HashMap<char, i64>,
+        send_queue: &mut VecDeque<i64>,
+        is_execution_0: bool,
+    ) {
+        if !is_execution_0 {
+            registers.entry('0').and_modify(|i| *i += 1).or_insert(1);
+        }
+
+        if register.is_ascii_digit() {
+            let digit = register.to_digit(10).unwrap() as i64;
+            send_queue.push_back(digit);
+        } else {
+            let value = registers.get(register).copied().unwrap_or(0);
+            send_queue.push_back(value);
+        }
+    }
+
+    fn rcv2(
+        &self,
+        register: &char,
+        registers: &mut HashMap<char, i64>,
+        recv_queue: &mut VecDeque<i64>,
+    ) -> i64 {
+        if let Some(entry) = recv_queue.pop_front() {
+            registers.insert(*register, entry);
+            1
+        } else {
+            0
+        }
+    }
+}
+
+fn next_register<'a>(
+    source: &str,
+    split: &mut impl Iterator<Item = &'a str>,
+) -> Result<char, ParseError> {
+    let token = next_token(source, split, "a register")?;
+    let mut chars = token.chars();
+    match (chars.next(), chars.next()) {
+        (Some(register), None) => Ok(register),
+        _ => Err(ParseError::new(source, token, "expected a register, found")),
+    }
+}
+
+fn next_value<'a>(
+    source: &str,
+    split: &mut impl Iterator<Item = &'a str>,
+) -> Result<RegisterValue, ParseError> {
+    parse_within(source, next_token(source, split, "a register or a number")?)
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split_whitespace();
+        let name = next_token(s, &mut split, "an instruction")?;
+
+        let instruction = match name {
+            "snd" => Instruction::Snd {
+                register: next_register(s, &mut split)?,
+            },
+            "set" => Instruction::Set {
+                register: next_register(s, &mut split)?,
+                value: next_value(s, &mut split)?,
+            },
+            "add" => Instruction::Add {
+                register: next_register(s, &mut split)?,
+                value: next_value(s, &mut split)?,
+            },
+            "mul" => Instruction::Mul {
+                register: next_register(s, &mut split)?,
+                value: next_value(s, &mut split)?,
+            },
+            "mod" => Instruction::Mod {
+                register: next_register(s, &mut split)?,
+                value: next_value(s, &mut split)?,
+            },
+            "rcv" => Instruction::Rcv {
+                register: next_register(s, &mut split)?,
+            },
+            "jgz" => Instruction::Jgz {
+                value_1: next_value(s, &mut split)?,
+                value_2: next_value(s, &mut split)?,
+            },
+            _ => return Err(ParseError::new(s, name, "unknown instruction")),
+        };
+
+        if let Some(token) = split.next() {
+            return Err(ParseError::new(s, token, "unexpected"));
+        }
+        Ok(instruction)
+    }
+}
+
+pub struct Day18 {
+    instructions: Vec<Instruction>,
+}
+
+impl Solution for Day18 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let instructions: Vec<Instruction> = parse_lines(input)?;
+        if instructions.is_empty() {
+            return Err(ParseError::at_end(input, "expected an instruction"));
+        }
+
+        Ok(Self { instructions })
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let mut registers: HashMap<char, i64> = HashMap::new();
+
+        // Solve
+        let mut current_positon: i64 = 0;
+        let mut jmp;
+        let mut rcv = 0;
+        while rcv == 0 {
+            // The program ends without recovering a frequency when it jumps
+            // outside of the instructions.
+            let Some(instuction) = self.instructions.get(current_positon as usize) else {
+                return Answer::Empty;
+            };
+            [jmp, rcv] = instuction.execute(&mut registers);
+
+            current_positon += jmp;
+        }
+
+        // Result
+        registers.get(&'0').copied().unwrap_or(0).into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Preamble
+        let mut registers_0: HashMap<char, i64> = HashMap::new();
+        registers_0.insert('p', 0);
+
+        let mut registers_1: HashMap<char, i64> = HashMap::new();
+        registers_1.insert('p', 1);
+        registers_1.insert('0', 0);
+
+        let instructions = &self.instructions;
+
+        // Solve
+        let mut is_execution_0 = true;
+        let mut current_position_0: i64 = 0;
+        let mut current_position_1: i64 = 0;
+        let mut jmp;
+        let mut deadlock = false;
+        let mut queue_0: VecDeque<i64> = VecDeque::new();
+        let mut queue_1: VecDeque<i64> = VecDeque::new();
+        let mut first_change = true;
+        while !deadlock {
+            // A program that jumped outside of the instructions has
+            // terminated and waits forever, like one blocked on rcv.
+            if is_execution_0 {
+                jmp = match instructions.get(current_position_0 as usize) {
+                    Some(instruction) => instruction.execute2(
+                        &mut registers_0,
+                        &mut queue_0,
+                        &mut queue_1,
+                        is_execution_0,
+                    ),
+                    None => 0,
+                };
+                current_position_0 += jmp;
+            } else {
+                jmp = match instructions.get(current_position_1 as usize) {
+                    Some(instruction) => instruction.execute2(
+                        &mut registers_1,
+                        &mut queue_1,
+                        &mut queue_0,
+                        is_execution_0,
+                    ),
+                    None => 0,
+                };
+                current_position_1 += jmp;
+            }
+
+            if jmp == 0 {
+                is_execution_0 = !is_execution_0;
+
+                if first_change {
+                    first_change = false;
+                    continue;
+                }
+
+                let terminated = |position: i64| instructions.get(position as usize).is_none();
+                deadlock = if is_execution_0 {
+                    terminated(current_position_0) || queue_0.is_empty()
+                } else {
+                    terminated(current_position_1) || queue_1.is_empty()
+                };
+            }
+        }
+
+        // Result
+        registers_1.get(&'0').copied().unwrap_or(0).into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day18;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day18::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test_2(file!()).unwrap();
+        println!("{}", Day18::parse(input.text()).unwrap().part2());
+    }
+}
@@ -0,0 +1,102 @@
+use utils::direction::Direction;
+use utils::grid::Grid;
+use utils::grid_point::GridPoint;
+use utils::grid_walker::GridWalker;
+use utils::parse::ParseError;
+use utils::solution::{Answer, Solution};
+
+fn is_path(cell: Option<&char>) -> bool {
+    cell.is_some_and(|i| *i != ' ')
+}
+
+fn walk(grid: &Grid<char>, start: GridPoint) -> (String, usize) {
+    // Preamble
+    let mut letters = String::new();
+    let mut steps: usize = 1;
+
+    // Prepare
+    let mut walker = GridWalker::new(grid, start, Direction::Down);
+
+    // Solve
+    loop {
+        if grid.get_from_point(&walker.position()) == Some(&'+') {
+            let open = |point: Option<GridPoint>| {
+                point.is_some_and(|p| is_path(grid.get_from_point(&p)) && !walker.has_visited(&p))
+            };
+            if open(walker.to_left()) {
+                walker.turn_left();
+            } else if open(walker.to_right()) {
+                walker.turn_right();
+            }
+        }
+
+        if !is_path(walker.peek(grid)) || !walker.r#move() {
+            break;
+        }
+
+        let c = grid.get_from_point(&walker.position()).unwrap();
+        if c.is_alphabetic() {
+            letters.push(*c);
+        }
+
+        steps += 1;
+    }
+
+    (letters, steps)
+}
+
+pub struct Day19 {
+    grid: Grid<char>,
+    start: GridPoint,
+}
+
+impl Solution for Day19 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse_padded(input, ' ', |c| c);
+        let x = grid
+            .rows()
+            .next()
+            .and_then(|row| row.iter().position(|i| *i != ' '))
+            .ok_or_else(|| match input.lines().next() {
+                Some(line) => {
+                    ParseError::new(input, line, "expected a path on the first line, found")
+                }
+                None => ParseError::at_end(input, "expected a path"),
+            })?;
+
+        Ok(Self {
+            grid,
+            start: GridPoint { x, y: 0 },
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        let (result, _) = walk(&self.grid, self.start);
+        result.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let (_, result) = walk(&self.grid, self.start);
+        result.into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day19;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day19::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day19::parse(input.text()).unwrap().part2());
+    }
+}
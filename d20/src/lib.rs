@@ -0,0 +1,134 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use regex::Regex;
+use utils::parse::{parse_lines, parse_token, ParseError};
+use utils::solution::{Answer, Solution};
+use utils::vector::Vec3;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Particles {
+    position: Vec3,
+    velocity: Vec3,
+    acceleration: Vec3,
+}
+impl Particles {
+    fn tick(&mut self) {
+        self.velocity += self.acceleration;
+        self.position += self.velocity;
+    }
+}
+
+impl std::fmt::Debug for Particles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [p_x, p_y, p_z] = self.position.0;
+        let [v_x, v_y, v_z] = self.velocity.0;
+        let [a_x, a_y, a_z] = self.acceleration.0;
+        f.write_fmt(format_args!(
+            "p=<{},{},{}>, v=<{},{},{}>, a=<{},{},{}>",
+            p_x, p_y, p_z, v_x, v_y, v_z, a_x, a_y, a_z
+        ))
+    }
+}
+
+impl FromStr for Particles {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r#"p=< ?(-?\d+), ?(-?\d+), ?(-?\d+)>, v=< ?(-?\d+), ?(-?\d+), ?(-?\d+)>, a=< ?(-?\d+), ?(-?\d+), ?(-?\d+)>"#).unwrap();
+        let Some(captures) = re.captures(s) else {
+            return Err(ParseError::new(
+                s,
+                s.trim(),
+                "expected p=<x,y,z>, v=<x,y,z>, a=<x,y,z>, found",
+            ));
+        };
+        let value = |i: usize| parse_token(s, captures.get(i).unwrap().as_str());
+        let vector = |i: usize| -> Result<Vec3, ParseError> {
+            Ok(Vec3::new([value(i)?, value(i + 1)?, value(i + 2)?]))
+        };
+
+        Ok(Particles {
+            position: vector(1)?,
+            velocity: vector(4)?,
+            acceleration: vector(7)?,
+        })
+    }
+}
+
+pub struct Day20 {
+    particles: Vec<Particles>,
+}
+
+impl Solution for Day20 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let particles: Vec<Particles> = parse_lines(input)?;
+        if particles.is_empty() {
+            return Err(ParseError::at_end(input, "expected a particle"));
+        }
+
+        Ok(Self { particles })
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let mut accelation_sums: Vec<i64> = Vec::new();
+
+        for particle in self.particles.iter() {
+            let accelation_sum = particle.acceleration.manhattan(&Vec3::default());
+            accelation_sums.push(accelation_sum)
+        }
+
+        // Solve
+        let (result, _) = accelation_sums
+            .iter()
+            .enumerate()
+            .min_by_key(|i| i.1)
+            .unwrap();
+
+        // Result
+        result.into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Preamble
+        let mut particles = self.particles.clone();
+
+        // Solve
+        for _ in 0..40 {
+            let mut hit_set: HashSet<Vec3> = HashSet::with_capacity(particles.len());
+            let mut delete_set: HashSet<Vec3> = HashSet::new();
+            for particel in particles.iter_mut() {
+                particel.tick();
+                if !hit_set.insert(particel.position) {
+                    delete_set.insert(particel.position);
+                }
+            }
+
+            particles.retain(|particel| !delete_set.contains(&particel.position));
+        }
+
+        // Result
+        particles.len().into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day20;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day20::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test_2(file!()).unwrap();
+        println!("{}", Day20::parse(input.text()).unwrap().part2());
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use utils::grid::Grid;
+use utils::params;
+use utils::params::Params;
+use utils::parse::{parse_lines, ParseError};
+use utils::solution::{Answer, Solution};
+
+params! {
+    pub struct Day21Params {
+        pub part1_iterations: usize = 5,
+        pub part2_iterations: usize = 18,
+    }
+}
+
+fn create_new_grid(grid: &Grid<u8>, rule_map: &HashMap<Grid<u8>, Grid<u8>>) -> Grid<u8> {
+    let sub_grid_size = if grid.get_max_y().is_multiple_of(2) {
+        2
+    } else {
+        3
+    };
+
+    let tiles = grid
+        .tiles(sub_grid_size)
+        .unwrap()
+        .map(|(position, sub_grid)| {
+            // `parse` checks that every pattern has a rule.
+            (position, rule_map[&sub_grid].clone())
+        });
+
+    Grid::from_tiles(tiles).expect("every rule grows its tile by one")
+}
+
+fn start_grid() -> Grid<u8> {
+    ".#.\n..#\n###".parse().unwrap()
+}
+
+/// Parses a square pattern like `.#/#.`, `pattern` is a slice of `source`.
+fn parse_pattern(source: &str, pattern: &str) -> Result<Grid<u8>, ParseError> {
+    if let Some((i, c)) = pattern
+        .char_indices()
+        .find(|(_, c)| !matches!(c, '.' | '#' | '/'))
+    {
+        return Err(ParseError::new(
+            source,
+            &pattern[i..i + c.len_utf8()],
+            "expected . or #, found",
+        ));
+    }
+
+    let rows: Vec<&str> = pattern.split('/').collect();
+    if rows.iter().any(|row| row.len() != rows.len()) {
+        return Err(ParseError::new(
+            source,
+            pattern,
+            "expected a square pattern, found",
+        ));
+    }
+
+    Ok(Grid::parse(&pattern.replace('/', "\n"), |c| c as u8))
+}
+
+/// Every 2x2 and 3x3 pattern the grid can be split into.
+fn all_patterns() -> impl Iterator<Item = Grid<u8>> {
+    [2usize, 3].into_iter().flat_map(|size| {
+        (0..1u32 << (size * size)).map(move |bits| {
+            let cells = (0..size * size)
+                .map(|i| if bits >> i & 1 == 1 { b'#' } else { b'.' })
+                .collect();
+            Grid::from_vec(size, cells).unwrap()
+        })
+    })
+}
+
+/// Writes `grid` the way the rules do, like `.#/#.`.
+fn format_pattern(grid: &Grid<u8>) -> String {
+    grid.rows()
+        .map(|row| row.iter().map(|c| *c as char).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+struct Rule {
+    left: Grid<u8>,
+    right: Grid<u8>,
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = s.trim();
+        let (left, right) = line.split_once(" => ").ok_or_else(|| {
+            ParseError::new(s, line, "expected two patterns separated by =>, found")
+        })?;
+
+        let rule = Self {
+            left: parse_pattern(s, left)?,
+            right: parse_pattern(s, right)?,
+        };
+
+        let size = rule.left.width();
+        if !(2..=3).contains(&size) {
+            return Err(ParseError::new(
+                s,
+                left,
+                "expected a 2x2 or 3x3 pattern, found",
+            ));
+        }
+        if rule.right.width() != size + 1 {
+            return Err(ParseError::new(
+                s,
+                right,
+                format!("expected a {0}x{0} pattern, found", size + 1),
+            ));
+        }
+
+        Ok(rule)
+    }
+}
+
+pub struct Day21 {
+    rule_map: HashMap<Grid<u8>, Grid<u8>>,
+    params: Day21Params,
+}
+
+impl Day21 {
+    fn enhance(&self, iterations: usize) -> usize {
+        let mut grid = start_grid();
+
+        for _ in 0..iterations {
+            grid = create_new_grid(&grid, &self.rule_map);
+        }
+
+        // println!("{grid}");
+        grid.count_for(&b'#')
+    }
+}
+
+impl Solution for Day21 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut rule_map: HashMap<Grid<u8>, Grid<u8>> = HashMap::new();
+
+        for Rule { left, right } in parse_lines(input)? {
+            for variant in left.symmetries() {
+                rule_map.entry(variant).or_insert_with(|| right.clone());
+            }
+        }
+
+        if let Some(pattern) = all_patterns().find(|pattern| !rule_map.contains_key(pattern)) {
+            return Err(ParseError::at_end(
+                input,
+                format!(
+                    "expected a rule for {}",
+                    format_pattern(&pattern.canonical())
+                ),
+            ));
+        }
+
+        Ok(Self {
+            rule_map,
+            params: Day21Params::default(),
+        })
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        self.params.set(name, value)
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        self.params.values()
+    }
+
+    fn part1(&self) -> Answer {
+        self.enhance(self.params.part1_iterations).into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.enhance(self.params.part2_iterations).into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::{Day21, Day21Params};
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        let mut day = Day21::parse(input.text()).unwrap();
+        day.params = Day21Params {
+            part1_iterations: 2,
+            part2_iterations: 2,
+        };
+        println!("{}", day.part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        let mut day = Day21::parse(input.text()).unwrap();
+        day.params = Day21Params {
+            part1_iterations: 2,
+            part2_iterations: 2,
+        };
+        println!("{}", day.part2());
+    }
+}
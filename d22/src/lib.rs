@@ -0,0 +1,166 @@
+use utils::direction::Direction;
+use utils::hash_point_map::HashPointMap;
+use utils::params;
+use utils::params::Params;
+use utils::parse::ParseError;
+use utils::point::{MapPoint, MapWalker};
+use utils::solution::{Answer, Solution};
+
+params! {
+    pub struct Day22Params {
+        pub part1_bursts: usize = 10_000,
+        pub part2_bursts: usize = 10_000_000,
+    }
+}
+
+pub struct Day22 {
+    point_list: Vec<MapPoint>,
+    mid_x: i64,
+    mid_y: i64,
+    params: Day22Params,
+}
+
+impl Day22 {
+    fn create_map(&self, value: u8) -> HashPointMap<u8> {
+        let mut hash_point_map: HashPointMap<u8> = HashPointMap::default();
+        for point in self.point_list.iter() {
+            hash_point_map.push(*point, value);
+        }
+        hash_point_map
+    }
+
+    fn create_walker(&self) -> MapWalker {
+        MapWalker {
+            direction: Direction::Up,
+            position: MapPoint {
+                x: self.mid_x,
+                y: self.mid_y,
+            },
+        }
+    }
+}
+
+impl Solution for Day22 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut point_list = Vec::new();
+
+        let mut max_x = 0;
+        let mut max_y = 0;
+
+        for (row, line) in input.lines().enumerate() {
+            let line = line.trim();
+            max_x = max_x.max(line.len());
+            max_y = max_y.max(row);
+            for (column, ch) in line.chars().enumerate() {
+                if ch == '#' {
+                    point_list.push(MapPoint::new(column as i64, row as i64));
+                }
+            }
+        }
+
+        // Prepare
+        let point_list = point_list
+            .into_iter()
+            .map(|point| MapPoint::new(point.x, max_y as i64 - point.y))
+            .collect();
+
+        Ok(Self {
+            point_list,
+            mid_x: (max_x / 2) as i64,
+            mid_y: (max_y / 2) as i64,
+            params: Day22Params::default(),
+        })
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        self.params.set(name, value)
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        self.params.values()
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let mut hash_point_map = self.create_map(b'#');
+        let mut walker = self.create_walker();
+
+        // Solve
+        let mut result: usize = 0;
+        for _ in 0..self.params.part1_bursts {
+            match hash_point_map.get(&walker.position) {
+                Some(_) => {
+                    hash_point_map.remove(&walker.position);
+                    walker.turn_right();
+                }
+                None => {
+                    result += 1;
+                    hash_point_map.push(walker.position, b'#');
+                    walker.turn_left();
+                }
+            }
+
+            walker.r#move()
+        }
+
+        // Result
+        result.into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Preamble
+        let mut hash_point_map = self.create_map(b'I');
+        let mut walker = self.create_walker();
+
+        // Solve
+        let mut result: usize = 0;
+        for _ in 0..self.params.part2_bursts {
+            match hash_point_map.get(&walker.position) {
+                Some(data) => match data {
+                    b'W' => {
+                        hash_point_map.push(walker.position, b'I');
+                        result += 1;
+                    }
+                    b'I' => {
+                        hash_point_map.push(walker.position, b'F');
+                        walker.turn_right();
+                    }
+                    b'F' => {
+                        hash_point_map.remove(&walker.position);
+                        walker.turn_around();
+                    }
+                    _ => panic!("Should not happen"),
+                },
+                None => {
+                    hash_point_map.push(walker.position, b'W');
+                    walker.turn_left();
+                }
+            }
+
+            walker.r#move()
+        }
+
+        // Result
+        result.into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day22;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day22::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day22::parse(input.text()).unwrap().part2());
+    }
+}
@@ -0,0 +1,229 @@
+use std::str::FromStr;
+
+use utils::num::is_prime;
+use utils::parse::{next_token, parse_lines, parse_within, ParseError};
+use utils::solution::{Answer, Solution};
+
+#[derive(Debug, Copy, Clone)]
+enum Parameter {
+    Number(i64),
+    Register(usize),
+}
+impl Parameter {
+    fn get_value(&self, context: &Context) -> i64 {
+        match self {
+            Parameter::Number(value) => *value,
+            Parameter::Register(register) => context.registers[*register],
+        }
+    }
+    fn set_value(&self, value: i64, context: &mut Context) {
+        match self {
+            Parameter::Number(_) => panic!("Should not be here"),
+            Parameter::Register(register) => {
+                context.registers[*register] = value;
+            }
+        };
+    }
+}
+
+impl FromStr for Parameter {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(value) = s.parse() {
+            return Ok(Self::Number(value));
+        }
+
+        match s.as_bytes() {
+            [register @ b'a'..=b'h'] => Ok(Self::Register((register - b'a') as usize)),
+            _ => Err(ParseError::new(
+                s,
+                s,
+                "expected a register a-h or a number, found",
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Instruction {
+    Set(Parameter, Parameter),
+    Jnz(Parameter, Parameter),
+    Sub(Parameter, Parameter),
+    Mul(Parameter, Parameter),
+}
+
+struct Context {
+    registers: [i64; 8],
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split_whitespace();
+
+        let instruction = next_token(s, &mut split, "an instruction")?;
+        let parameter1: Parameter = parse_within(s, next_token(s, &mut split, "a parameter")?)?;
+        let parameter2: Parameter = parse_within(s, next_token(s, &mut split, "a parameter")?)?;
+        if let Some(token) = split.next() {
+            return Err(ParseError::new(s, token, "unexpected"));
+        }
+
+        match instruction {
+            "set" => Ok(Instruction::Set(parameter1, parameter2)),
+            "jnz" => Ok(Instruction::Jnz(parameter1, parameter2)),
+            "sub" => Ok(Instruction::Sub(parameter1, parameter2)),
+            "mul" => Ok(Instruction::Mul(parameter1, parameter2)),
+            _ => Err(ParseError::new(s, instruction, "unknown instruction")),
+        }
+    }
+}
+
+/// What the program counts into `h` from instruction 11 on: how many of `b`,
+/// `b + 17`, … up to `c` are not prime. The program finds no factors for a
+/// `b` below 2, so those do not count.
+fn count_composites(start: i64, end: i64) -> i64 {
+    (start..=end)
+        .step_by(17)
+        .filter(|b| *b >= 2 && !is_prime(*b as u64))
+        .count() as i64
+}
+
+pub struct Day23 {
+    instractions: Vec<Instruction>,
+}
+
+impl Solution for Day23 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            instractions: parse_lines(input)?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let mut context: Context = Context { registers: [0; 8] };
+        let mut called_mul: usize = 0;
+
+        // Solve
+        let mut idx: usize = 0;
+        while let Some(instruction) = self.instractions.get(idx) {
+            match instruction {
+                Instruction::Set(p1, p2) => {
+                    let value = p2.get_value(&context);
+                    p1.set_value(value, &mut context);
+                }
+                Instruction::Jnz(p1, p2) => {
+                    let value = p1.get_value(&context);
+                    if value != 0 {
+                        let value_2 = p2.get_value(&context);
+                        idx = (idx as i64 + value_2) as usize;
+                        continue;
+                    }
+                }
+                Instruction::Sub(p1, p2) => {
+                    let value_1 = p1.get_value(&context);
+                    let value_2 = p2.get_value(&context);
+                    p1.set_value(value_1 - value_2, &mut context);
+                }
+                Instruction::Mul(p1, p2) => {
+                    let value_1 = p1.get_value(&context);
+                    let value_2 = p2.get_value(&context);
+                    p1.set_value(value_1 * value_2, &mut context);
+                    called_mul += 1;
+                }
+            };
+            idx += 1;
+        }
+
+        // Result
+        called_mul.into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Preamble
+        let mut context: Context = Context {
+            registers: [1, 0, 0, 0, 0, 0, 0, 0],
+        };
+
+        // Solve
+        let mut idx: usize = 0;
+        while let Some(instruction) = self.instractions.get(idx) {
+            if idx < 11 {
+                match instruction {
+                    Instruction::Set(p1, p2) => {
+                        let value = p2.get_value(&context);
+                        p1.set_value(value, &mut context);
+                    }
+                    Instruction::Jnz(p1, p2) => {
+                        let value = p1.get_value(&context);
+                        if value != 0 {
+                            let value_2 = p2.get_value(&context);
+                            idx = (idx as i64 + value_2) as usize;
+                            continue;
+                        }
+                    }
+                    Instruction::Sub(p1, p2) => {
+                        let value_1 = p1.get_value(&context);
+                        let value_2 = p2.get_value(&context);
+                        p1.set_value(value_1 - value_2, &mut context);
+                    }
+                    Instruction::Mul(p1, p2) => {
+                        let value_1 = p1.get_value(&context);
+                        let value_2 = p2.get_value(&context);
+                        p1.set_value(value_1 * value_2, &mut context);
+                    }
+                };
+                idx += 1;
+            } else {
+                let start = context.registers[1];
+                let end = context.registers[2];
+                context.registers[7] = count_composites(start, end);
+                idx = usize::MAX;
+            }
+        }
+
+        // Result
+        context.registers[7].into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::{count_composites, Day23};
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day23::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day23::parse(input.text()).unwrap().part2());
+    }
+
+    #[test]
+    fn test_count_composites() {
+        let is_composite = |b: i64| (2..).take_while(|d| d * d <= b).any(|d| b % d == 0);
+
+        for start in [-1_000, 0, 1, 2, 49, 121, 289, 1_000, 106_500] {
+            let end = start + 17 * 1_000;
+            let expected = (start..=end)
+                .step_by(17)
+                .filter(|b| is_composite(*b))
+                .count() as i64;
+            assert_eq!(count_composites(start, end), expected, "{start}..={end}");
+        }
+
+        // Squares of primes, counted as primes by a search that stops
+        // before the square root.
+        assert_eq!(count_composites(49, 49), 1);
+        assert_eq!(count_composites(289, 289 + 17), 2);
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use utils::parse::{next_token, parse_lines, parse_token, ParseError};
+use utils::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy)]
+struct Pair {
+    pub v1: usize,
+    pub v2: usize,
+}
+
+impl Pair {
+    pub fn has_value(&self, value: usize) -> bool {
+        self.v1 == value || self.v2 == value
+    }
+
+    fn has_zero(&self) -> bool {
+        self.v1 == 0 || self.v2 == 0
+    }
+
+    fn other_value(&self, value: usize) -> usize {
+        if self.v1 == value {
+            self.v2
+        } else {
+            self.v1
+        }
+    }
+
+    fn sum(&self) -> usize {
+        self.v1 + self.v2
+    }
+}
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.trim().split('/');
+        let v1 = parse_token(s, next_token(s, &mut split, "a port")?)?;
+        let v2 = parse_token(s, next_token(s, &mut split, "a port")?)?;
+        if let Some(token) = split.next() {
+            return Err(ParseError::new(s, token, "unexpected"));
+        }
+
+        Ok(Self { v1, v2 })
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct SearchContext {
+    next: usize,
+    bridge: u64,
+}
+impl SearchContext {
+    fn sum(&self, pairs: &[Pair]) -> usize {
+        sum_bridges(self.bridge, pairs)
+    }
+}
+
+fn sum_bridges(bridge: u64, pairs: &[Pair]) -> usize {
+    pairs
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| 1 << i & bridge != 0)
+        .map(|(_, pair)| pair.sum())
+        .sum()
+}
+
+pub struct Day24 {
+    pairs: Vec<Pair>,
+}
+
+impl Day24 {
+    fn search(&self, mut on_end: impl FnMut(SearchContext)) {
+        let pairs = &self.pairs;
+
+        for (start_idx, start_pair) in pairs.iter().enumerate() {
+            if !start_pair.has_zero() {
+                continue;
+            }
+
+            let start = SearchContext {
+                bridge: 1 << start_idx,
+                next: start_pair.other_value(0),
+            };
+
+            let mut queue = vec![start];
+
+            while let Some(item) = queue.pop() {
+                for (c_idx, pair) in pairs.iter().enumerate() {
+                    let shift_idx = 1 << c_idx;
+                    if shift_idx & item.bridge != 0 {
+                        continue;
+                    }
+
+                    if pair.has_value(item.next) {
+                        let sc = SearchContext {
+                            next: pair.other_value(item.next),
+                            bridge: item.bridge + shift_idx,
+                        };
+                        queue.push(sc);
+                    }
+
+                    on_end(item);
+                }
+            }
+        }
+    }
+}
+
+impl Solution for Day24 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let pairs: Vec<Pair> = parse_lines(input)?;
+        // A bridge is a bit set in a u64.
+        if let Some(line) = input.lines().nth(u64::BITS as usize) {
+            return Err(ParseError::new(
+                input,
+                line,
+                "expected at most 64 components, found another",
+            ));
+        }
+
+        Ok(Self { pairs })
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let mut to_calc: HashSet<u64> = HashSet::new();
+
+        // Solve
+        self.search(|item| {
+            to_calc.insert(item.bridge);
+        });
+
+        // Result
+        let mut result = 0;
+
+        for calc in to_calc {
+            result = result.max(sum_bridges(calc, &self.pairs));
+        }
+
+        result.into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Preamble
+        let mut to_calc = Vec::new();
+
+        // Solve
+        self.search(|item| to_calc.push(item));
+
+        // Result
+        let mut result = 0;
+        let mut length = 0;
+
+        for calc in to_calc {
+            let ones = calc.bridge.count_ones();
+            if length == ones {
+                result = result.max(calc.sum(&self.pairs));
+            }
+            if length < ones {
+                result = calc.sum(&self.pairs);
+                length = ones;
+            }
+        }
+
+        result.into()
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day24;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day24::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day24::parse(input.text()).unwrap().part2());
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+
+use utils::parse::{next_token, parse_token, ParseError};
+use utils::solution::{Answer, Solution};
+
+#[derive(Debug, Clone)]
+struct Context {
+    position: i64,
+    state: u8,
+    tape: HashMap<i64, bool>,
+    steps: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[allow(dead_code)]
+struct State {
+    state: u8,
+    zero_write: bool,
+    zero_move: i8,
+    zero_continue: u8,
+    one_write: bool,
+    one_move: i8,
+    one_continue: u8,
+}
+
+fn last_word(line: &str) -> &str {
+    line.split_whitespace()
+        .next_back()
+        .unwrap_or(line)
+        .trim_end_matches(['.', ':'])
+}
+
+fn parse_state(input: &str, line: &str) -> Result<u8, ParseError> {
+    let word = last_word(line);
+    match word.as_bytes() {
+        [name] if name.is_ascii_uppercase() => Ok(name - b'A'),
+        _ => Err(ParseError::new(input, word, "expected a state name, found")),
+    }
+}
+
+fn parse_write(input: &str, line: &str) -> Result<bool, ParseError> {
+    match last_word(line) {
+        "0" => Ok(false),
+        "1" => Ok(true),
+        word => Err(ParseError::new(input, word, "expected 0 or 1, found")),
+    }
+}
+
+fn parse_move(input: &str, line: &str) -> Result<i8, ParseError> {
+    match last_word(line) {
+        "right" => Ok(1),
+        "left" => Ok(-1),
+        word => Err(ParseError::new(
+            input,
+            word,
+            "expected left or right, found",
+        )),
+    }
+}
+
+fn parse_file(input: &str) -> Result<(Context, Vec<State>), ParseError> {
+    let mut lines = input.lines();
+
+    let start_line = next_token(input, &mut lines, "the start state")?;
+    let state = parse_state(input, start_line)?;
+    let mut next_states = vec![(state, start_line)];
+
+    let steps_line = next_token(input, &mut lines, "the number of steps")?;
+    let steps = parse_token(
+        input,
+        steps_line
+            .split_whitespace()
+            .nth_back(1)
+            .unwrap_or(steps_line),
+    )?;
+
+    let mut states = Vec::new();
+
+    while let Some(line) = lines.next() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let in_state = parse_state(input, line)?;
+        if in_state as usize != states.len() {
+            return Err(ParseError::new(
+                input,
+                last_word(line),
+                format!(
+                    "expected state {}, found",
+                    (b'A' + states.len() as u8) as char
+                ),
+            ));
+        }
+        next_token(input, &mut lines, "the rule for 0")?;
+        let zero_write = parse_write(input, next_token(input, &mut lines, "a value to write")?)?;
+        let zero_move = parse_move(input, next_token(input, &mut lines, "a move")?)?;
+        let zero_continue_line = next_token(input, &mut lines, "a next state")?;
+        let zero_continue = parse_state(input, zero_continue_line)?;
+        next_token(input, &mut lines, "the rule for 1")?;
+        let one_write = parse_write(input, next_token(input, &mut lines, "a value to write")?)?;
+        let one_move = parse_move(input, next_token(input, &mut lines, "a move")?)?;
+        let one_continue_line = next_token(input, &mut lines, "a next state")?;
+        let one_continue = parse_state(input, one_continue_line)?;
+        next_states.push((zero_continue, zero_continue_line));
+        next_states.push((one_continue, one_continue_line));
+
+        states.push(State {
+            state: in_state,
+            zero_write,
+            zero_move,
+            zero_continue,
+            one_write,
+            one_move,
+            one_continue,
+        });
+    }
+
+    for (next_state, line) in next_states {
+        if next_state as usize >= states.len() {
+            return Err(ParseError::new(input, last_word(line), "unknown state"));
+        }
+    }
+
+    Ok((
+        Context {
+            position: 0,
+            state,
+            tape: HashMap::new(),
+            steps,
+        },
+        states,
+    ))
+}
+
+pub struct Day25 {
+    context: Context,
+    states: Vec<State>,
+}
+
+impl Solution for Day25 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (context, states) = parse_file(input)?;
+        Ok(Self { context, states })
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let mut context = self.context.clone();
+        let states = &self.states;
+
+        // Solve
+        for _ in 0..context.steps {
+            let state = &states[context.state as usize];
+            let value = context.tape.entry(context.position).or_insert(false);
+
+            if *value {
+                context.position += state.one_move as i64;
+                *value = state.one_write;
+                context.state = state.one_continue;
+            } else {
+                context.position += state.zero_move as i64;
+                *value = state.zero_write;
+                context.state = state.zero_continue;
+            }
+        }
+
+        // Result
+        context.tape.values().filter(|i| **i).count().into()
+    }
+
+    fn part2(&self) -> Answer {
+        Answer::Empty
+    }
+}
+
+#[cfg(test)]
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
+
+    use crate::Day25;
+
+    #[test]
+    fn test_input_part_1() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day25::parse(input.text()).unwrap().part1());
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day25::parse(input.text()).unwrap().part2());
+    }
+}
@@ -1,79 +1,109 @@
-use std::{process::Command, time::Instant};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
 
-#[cfg(target_os = "linux")]
-fn get_commands() -> Vec<String> {
-    Vec::from([
-        String::from("target/release/d01"),
-        String::from("target/release/d02"),
-        String::from("target/release/d03"),
-        String::from("target/release/d04"),
-        String::from("target/release/d05"),
-        String::from("target/release/d06"),
-        String::from("target/release/d07"),
-        String::from("target/release/d08"),
-        String::from("target/release/d09"),
-        String::from("target/release/d10"),
-        String::from("target/release/d11"),
-        String::from("target/release/d12"),
-        String::from("target/release/d13"),
-        String::from("target/release/d14"),
-        String::from("target/release/d15"),
-        String::from("target/release/d16"),
-        String::from("target/release/d17"),
-        String::from("target/release/d18"),
-        String::from("target/release/d19"),
-        String::from("target/release/d20"),
-        String::from("target/release/d21"),
-        String::from("target/release/d22"),
-        String::from("target/release/d23"),
-        String::from("target/release/d24"),
-        String::from("target/release/d25"),
-    ])
+use aoc::days::{get_day, Day, DAYS};
+use aoc::runner::{run_days, DayReport, Mode};
+use clap::Parser;
+
+#[derive(Parser)]
+#[command(name = "launch_all", about = "Run every day and report the answers")]
+struct Cli {
+    /// Only run these days, all days when omitted
+    #[arg(short, long)]
+    day: Vec<u8>,
+    /// Number of days solved at the same time
+    #[arg(short, long, default_value_t = 1)]
+    threads: usize,
+    /// Input name or path used for every day
+    #[arg(short, long, default_value = "input")]
+    input: String,
+    /// Run the release binaries instead of solving in this process
+    #[arg(long)]
+    subprocess: bool,
+    #[arg(long, default_value = "target/release")]
+    binary_dir: PathBuf,
 }
 
-#[cfg(target_os = "windows")]
-fn get_commands() -> Vec<String> {
-    Vec::from([
-        String::from("target/release/d01.exe"),
-        String::from("target/release/d02.exe"),
-        String::from("target/release/d03.exe"),
-        String::from("target/release/d04.exe"),
-        String::from("target/release/d05.exe"),
-        String::from("target/release/d06.exe"),
-        String::from("target/release/d07.exe"),
-        String::from("target/release/d08.exe"),
-        String::from("target/release/d09.exe"),
-        String::from("target/release/d10.exe"),
-        String::from("target/release/d11.exe"),
-        String::from("target/release/d12.exe"),
-        String::from("target/release/d13.exe"),
-        String::from("target/release/d14.exe"),
-        String::from("target/release/d15.exe"),
-        String::from("target/release/d16.exe"),
-        String::from("target/release/d17.exe"),
-        String::from("target/release/d18.exe"),
-        String::from("target/release/d19.exe"),
-        String::from("target/release/d20.exe"),
-        String::from("target/release/d21.exe"),
-        String::from("target/release/d22.exe"),
-        String::from("target/release/d23.exe"),
-        String::from("target/release/d24.exe"),
-        String::from("target/release/d25.exe"),
-    ])
+fn print_report(report: &DayReport) {
+    match &report.answers {
+        Ok(answers) => {
+            let answers: Vec<String> = answers
+                .iter()
+                .map(|(part, answer)| format!("part {part}: {answer}"))
+                .collect();
+            println!(
+                "Day {:02} took {} milliseconds - {}",
+                report.day,
+                report.duration.as_millis(),
+                answers.join(", ")
+            );
+        }
+        Err(failure) => {
+            println!(
+                "Day {:02} failed after {} milliseconds - {failure}",
+                report.day,
+                report.duration.as_millis()
+            );
+        }
+    }
+
+    for line in report.stderr.lines() {
+        println!("    {line}");
+    }
 }
 
-fn main() {
-    let commands = get_commands();
+fn main() -> ExitCode {
+    let cli = Cli::parse();
 
-    let overall_start = Instant::now();
-    for (idx, command) in commands.iter().enumerate() {
-        let start = Instant::now();
-        unsafe {
-            Command::new(command).output().unwrap_unchecked();
+    let mut days: Vec<&Day> = Vec::new();
+    if cli.day.is_empty() {
+        days.extend(DAYS.iter());
+    } else {
+        for number in cli.day.iter() {
+            match get_day(*number) {
+                Some(day) => days.push(day),
+                None => {
+                    eprintln!("There is no day {number}");
+                    return ExitCode::FAILURE;
+                }
+            }
         }
-        let stop = start.elapsed();
-        println!("Day {} took {} milliseconds", idx + 1, stop.as_millis());
     }
+
+    let mode = if cli.subprocess {
+        Mode::Subprocess {
+            binary_dir: cli.binary_dir,
+            input: cli.input,
+        }
+    } else {
+        Mode::InProcess { input: cli.input }
+    };
+
+    let overall_start = Instant::now();
+    let reports = run_days(&days, &mode, cli.threads);
     let overall_stop = overall_start.elapsed();
-    println!("Overall it took {} milliseconds or {} seconds", overall_stop.as_millis(), overall_stop.as_secs());
+
+    for report in reports.iter() {
+        print_report(report);
+    }
+
+    let failed: Vec<String> = reports
+        .iter()
+        .filter(|report| !report.is_success())
+        .map(|report| format!("{:02}", report.day))
+        .collect();
+
+    println!(
+        "Overall it took {} milliseconds or {} seconds",
+        overall_stop.as_millis(),
+        overall_stop.as_secs()
+    );
+
+    if failed.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        println!("Failed days: {}", failed.join(", "));
+        ExitCode::FAILURE
+    }
 }
@@ -1,55 +1,49 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use utils::parse::ParseError;
+use utils::solution::{Answer, Solution};
 
-use utils::get_input_path;
-
-fn run(input_file: &str) {
-    // Preamble
-
-    // Parse
-    let file = File::open(input_file).unwrap();
-    let reader = BufReader::new(file);
+pub struct Day00 {
+    lines: Vec<String>,
+}
 
-    for line in reader.lines() {
-        let line = line.unwrap().trim().to_string();
-        
+impl Solution for Day00 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            lines: input.lines().map(|line| line.trim().to_string()).collect(),
+        })
     }
 
-    // Solve
-    // Result
-    let result = 0;
-
-    println!("Result is {}", result);
-}
-
-fn run2(_input_file: &str) {}
+    fn part1(&self) -> Answer {
+        // Preamble
+        let result: usize = 0;
 
-fn main() {
-    let input_path = get_input_path(file!());
-    let input_file = input_path.to_str().unwrap();
+        // Solve
+        for _line in self.lines.iter() {}
 
-    println!("{:?}", input_file);
+        // Result
+        result.into()
+    }
 
-    run(input_file);
-    run2(input_file);
+    fn part2(&self) -> Answer {
+        Answer::Empty
+    }
 }
 
 #[cfg(test)]
-mod main_test {
-    use utils::get_test_input_path;
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
 
-    use crate::run;
-    use crate::run2;
+    use crate::Day00;
 
     #[test]
     fn test_input_part_1() {
-        let input_path = get_test_input_path(file!());
-        run(input_path.to_str().unwrap());
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day00::parse(input.text()).unwrap().part1());
     }
 
     #[test]
     fn test_input_part_2() {
-        let input_path = get_test_input_path(file!());
-        run2(input_path.to_str().unwrap());
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day00::parse(input.text()).unwrap().part2());
     }
 }
@@ -1,46 +1,49 @@
-use std::fs;
-
-use utils::get_input_path;
-
-fn run(input_file: &str) {
-    // Preamble
-    let mut result: usize = 0;
-    // Parse
-    let line = fs::read_to_string(input_file).unwrap().trim().to_string();
-    // Solve
-    // Result
-    println!("Result is {}", result);
-}
+use utils::parse::ParseError;
+use utils::solution::{Answer, Solution};
 
-fn run2(_input_file: &str) {
+pub struct Day00 {
+    line: String,
 }
 
-fn main() {
-    let input_path = get_input_path(file!());
-    let input_file = input_path.to_str().unwrap();
+impl Solution for Day00 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            line: input.trim().to_string(),
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        // Preamble
+        let result: usize = 0;
+        let _line = &self.line;
 
-    println!("{:?}", input_file);
+        // Solve
 
-    run(input_file);
-    run2(input_file);
+        // Result
+        result.into()
+    }
+
+    fn part2(&self) -> Answer {
+        Answer::Empty
+    }
 }
 
 #[cfg(test)]
-mod main_test {
-    use utils::get_test_input_path;
+mod lib_test {
+    use utils::input::Input;
+    use utils::solution::Solution;
 
-    use crate::run;
-    use crate::run2;
+    use crate::Day00;
 
     #[test]
     fn test_input_part_1() {
-        let input_path = get_test_input_path(file!());
-        run(input_path.to_str().unwrap());
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day00::parse(input.text()).unwrap().part1());
     }
 
     #[test]
     fn test_input_part_2() {
-        let input_path = get_test_input_path(file!());
-        run2(input_path.to_str().unwrap());
+        let input = Input::test(file!()).unwrap();
+        println!("{}", Day00::parse(input.text()).unwrap().part2());
     }
 }
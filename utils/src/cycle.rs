@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where the states of `x, step(x), step(step(x)), …` start to repeat: the
+/// state at index `mu` is the first one seen again, `lambda` steps later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub mu: usize,
+    pub lambda: usize,
+}
+
+impl Cycle {
+    /// The index of the first state that equals the state at index `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.mu {
+            n
+        } else {
+            self.mu + (n - self.mu) % self.lambda
+        }
+    }
+}
+
+/// Finds the cycle by remembering the index of every state, calls `step`
+/// exactly `mu + lambda` times. The states must come back eventually, or it
+/// runs forever, like the other variants.
+pub fn find_cycle<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut state = initial;
+    let mut index = 0;
+    loop {
+        if let Some(first) = seen.insert(state.clone(), index) {
+            return Cycle {
+                mu: first,
+                lambda: index - first,
+            };
+        }
+        state = step(&state);
+        index += 1;
+    }
+}
+
+/// Floyd's tortoise and hare, keeps two states at a time.
+pub fn find_cycle_floyd<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let half = step(&hare);
+        hare = step(&half);
+    }
+
+    let mut mu = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        mu += 1;
+    }
+
+    let mut lambda = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        lambda += 1;
+    }
+
+    Cycle { mu, lambda }
+}
+
+/// Brent's variant, keeps two states at a time and usually needs fewer steps
+/// than Floyd's.
+pub fn find_cycle_brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut lambda = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == lambda {
+            tortoise = hare.clone();
+            power *= 2;
+            lambda = 0;
+        }
+        hare = step(&hare);
+        lambda += 1;
+    }
+
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..lambda {
+        hare = step(&hare);
+    }
+
+    let mut mu = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        mu += 1;
+    }
+
+    Cycle { mu, lambda }
+}
+
+/// The state after `n` steps. Stops stepping as soon as a state repeats and
+/// takes the rest from the cycle, so `n` can be huge.
+pub fn nth_state<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut states: Vec<S> = Vec::new();
+    let mut state = initial;
+    for index in 0..n {
+        if let Some(first) = seen.get(&state) {
+            let cycle = Cycle {
+                mu: *first,
+                lambda: index - first,
+            };
+            return states.swap_remove(cycle.reduce(n));
+        }
+
+        let next = step(&state);
+        seen.insert(state.clone(), index);
+        states.push(state);
+        state = next;
+    }
+    state
+}
+
+#[cfg(test)]
+mod cycle_test {
+    use crate::cycle::{find_cycle, find_cycle_brent, find_cycle_floyd, nth_state, Cycle};
+
+    /// Steps until a state repeats, comparing each new state with all before.
+    fn brute_force(initial: u64, step: impl Fn(&u64) -> u64) -> Cycle {
+        let mut states = vec![initial];
+        loop {
+            let next = step(states.last().unwrap());
+            if let Some(mu) = states.iter().position(|s| *s == next) {
+                return Cycle {
+                    mu,
+                    lambda: states.len() - mu,
+                };
+            }
+            states.push(next);
+        }
+    }
+
+    #[test]
+    fn test_against_brute_force() {
+        for modulus in [1, 2, 7, 97, 1000, 4099] {
+            for c in [1, 3, 11] {
+                for initial in [0, 2, 5] {
+                    let step = |x: &u64| (x * x + c) % modulus;
+                    let expected = brute_force(initial, step);
+
+                    assert_eq!(find_cycle(initial, step), expected);
+                    assert_eq!(find_cycle_floyd(initial, step), expected);
+                    assert_eq!(find_cycle_brent(initial, step), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_step_count() {
+        let mut steps = 0;
+        let cycle = find_cycle(0, |x: &u32| {
+            steps += 1;
+            if *x < 10 {
+                x + 1
+            } else {
+                4
+            }
+        });
+
+        assert_eq!(cycle, Cycle { mu: 4, lambda: 7 });
+        assert_eq!(steps, 11);
+    }
+
+    #[test]
+    fn test_nth_state() {
+        let step = |x: &u64| (x * x + 1) % 255;
+        let mut state = 3;
+        for n in 0..300 {
+            assert_eq!(nth_state(3, step, n), state);
+            state = step(&state);
+        }
+
+        let cycle = find_cycle(3, step);
+        let n = 1_000_000_000_000;
+        let mut expected = 3;
+        for _ in 0..cycle.reduce(n) {
+            expected = step(&expected);
+        }
+        assert_eq!(nth_state(3, step, n), expected);
+    }
+}
@@ -0,0 +1,240 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::parse::ParseError;
+use crate::vector::Vec2;
+
+/// One of the eight directions on a square lattice, in clockwise order from
+/// `Up`. Which way `Up` points depends on the coordinates, see `grid_delta`
+/// and `map_delta`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+const ALL: [Direction; 8] = [
+    Direction::Up,
+    Direction::UpRight,
+    Direction::Right,
+    Direction::DownRight,
+    Direction::Down,
+    Direction::DownLeft,
+    Direction::Left,
+    Direction::UpLeft,
+];
+
+impl Direction {
+    /// The four straight directions, clockwise from `Up`.
+    pub fn get_directions() -> [Direction; 4] {
+        [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ]
+    }
+
+    /// All eight directions, clockwise from `Up`.
+    pub fn get_all_directions() -> [Direction; 8] {
+        ALL
+    }
+
+    /// The position in `get_all_directions`, straight directions are even.
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// Turns by `steps` eighths of a full turn, clockwise for positive steps.
+    pub fn rotate(&self, steps: i64) -> Self {
+        ALL[(self.index() as i64 + steps).rem_euclid(8) as usize]
+    }
+
+    pub fn turn_right(&self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(&self) -> Self {
+        self.rotate(-2)
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate(4)
+    }
+
+    /// The step in grid coordinates, where y grows downward.
+    pub fn grid_delta(&self) -> Vec2 {
+        let delta = self.map_delta();
+        Vec2::new([delta.x(), -delta.y()])
+    }
+
+    /// The step in map coordinates, where y grows upward.
+    pub fn map_delta(&self) -> Vec2 {
+        let (x, y) = match self {
+            Direction::Up => (0, 1),
+            Direction::UpRight => (1, 1),
+            Direction::Right => (1, 0),
+            Direction::DownRight => (1, -1),
+            Direction::Down => (0, -1),
+            Direction::DownLeft => (-1, -1),
+            Direction::Left => (-1, 0),
+            Direction::UpLeft => (-1, 1),
+        };
+        Vec2::new([x, y])
+    }
+
+    pub fn arrow(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::UpRight => '↗',
+            Direction::Right => '>',
+            Direction::DownRight => '↘',
+            Direction::Down => 'v',
+            Direction::DownLeft => '↙',
+            Direction::Left => '<',
+            Direction::UpLeft => '↖',
+        }
+    }
+
+    /// The compass name, `N`, `NE`, `E` and so on.
+    pub fn compass(&self) -> &'static str {
+        match self {
+            Direction::Up => "N",
+            Direction::UpRight => "NE",
+            Direction::Right => "E",
+            Direction::DownRight => "SE",
+            Direction::Down => "S",
+            Direction::DownLeft => "SW",
+            Direction::Left => "W",
+            Direction::UpLeft => "NW",
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.compass())
+    }
+}
+
+/// Arrows (`^>v<`), `UDLR` and `NESW`, letters in either case.
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' | 'U' | 'u' | 'N' | 'n' => Ok(Direction::Up),
+            '>' | 'R' | 'r' | 'E' | 'e' => Ok(Direction::Right),
+            'v' | 'D' | 'd' | 'S' | 's' => Ok(Direction::Down),
+            '<' | 'L' | 'l' | 'W' | 'w' => Ok(Direction::Left),
+            '↗' => Ok(Direction::UpRight),
+            '↘' => Ok(Direction::DownRight),
+            '↙' => Ok(Direction::DownLeft),
+            '↖' => Ok(Direction::UpLeft),
+            _ => Err(format!("{value} is not a direction")),
+        }
+    }
+}
+
+/// Everything `TryFrom<char>` takes, plus the diagonal compass names like
+/// `ne` or `SW`. Surrounding whitespace is ignored.
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let token = s.trim();
+        let mut chars = token.chars();
+        let direction = match (chars.next(), chars.next(), chars.next()) {
+            (Some(c), None, None) => Direction::try_from(c).ok(),
+            (Some(first), Some(second), None) => {
+                match (first.to_ascii_uppercase(), second.to_ascii_uppercase()) {
+                    ('N', 'E') => Some(Direction::UpRight),
+                    ('S', 'E') => Some(Direction::DownRight),
+                    ('S', 'W') => Some(Direction::DownLeft),
+                    ('N', 'W') => Some(Direction::UpLeft),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        direction.ok_or_else(|| ParseError::new(s, token, "expected a direction, found"))
+    }
+}
+
+#[cfg(test)]
+mod direction_test {
+    use crate::direction::Direction;
+    use crate::vector::Vec2;
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Up.rotate(1), Direction::UpRight);
+        assert_eq!(Direction::Up.rotate(-1), Direction::UpLeft);
+        assert_eq!(Direction::Right.rotate(19), Direction::DownLeft);
+        assert_eq!(Direction::Right.rotate(-10), Direction::Up);
+
+        for direction in Direction::get_all_directions() {
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_eq!(direction.rotate(8), direction);
+            assert_eq!(direction.opposite().map_delta(), -direction.map_delta());
+        }
+    }
+
+    #[test]
+    fn test_deltas() {
+        assert_eq!(Direction::Up.map_delta(), Vec2::new([0, 1]));
+        assert_eq!(Direction::Up.grid_delta(), Vec2::new([0, -1]));
+        assert_eq!(Direction::Right.grid_delta(), Vec2::new([1, 0]));
+        assert_eq!(Direction::DownLeft.map_delta(), Vec2::new([-1, -1]));
+        assert_eq!(Direction::DownLeft.grid_delta(), Vec2::new([-1, 1]));
+
+        for direction in Direction::get_all_directions() {
+            let delta = direction.map_delta();
+            assert_eq!(direction.is_diagonal(), delta.x() != 0 && delta.y() != 0);
+            assert_eq!(
+                direction.turn_right().map_delta(),
+                Vec2::new([delta.y(), -delta.x()])
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let parse = |s: &str| s.parse::<Direction>().unwrap();
+        for text in ["^>v<", "URDL", "urdl", "NESW", "nesw"] {
+            let parsed: Vec<Direction> = text.chars().map(|c| c.try_into().unwrap()).collect();
+            assert_eq!(parsed, Direction::get_directions());
+        }
+
+        assert_eq!(parse("ne"), Direction::UpRight);
+        assert_eq!(parse(" SW\n"), Direction::DownLeft);
+        assert_eq!(parse("Nw"), Direction::UpLeft);
+        assert_eq!(parse("se"), Direction::DownRight);
+        assert_eq!(parse("s"), Direction::Down);
+
+        for direction in Direction::get_all_directions() {
+            assert_eq!(parse(direction.compass()), direction);
+            assert_eq!(Direction::try_from(direction.arrow()), Ok(direction));
+        }
+
+        assert!("x".parse::<Direction>().is_err());
+        assert!("nn".parse::<Direction>().is_err());
+        assert!("".parse::<Direction>().is_err());
+        assert!("north".parse::<Direction>().is_err());
+        assert!(Direction::try_from('0').is_err());
+    }
+}
@@ -0,0 +1,306 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Display, Write};
+use std::hash::Hash;
+use std::str::FromStr;
+
+use crate::parse::{parse_token, ParseError};
+
+/// A directed graph over arbitrary labels. Every label gets a dense id in
+/// the order it was added, the traversals work on these ids.
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    ids: HashMap<N, usize>,
+    labels: Vec<N>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Self {
+            ids: HashMap::new(),
+            labels: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    /// Parses lines like `0 <-> 2, 3` with `separator` between the node and
+    /// its comma separated neighbours. Each neighbour gets an edge from the
+    /// node, the list may be empty.
+    pub fn parse_adjacency(input: &str, separator: &str) -> Result<Self, ParseError>
+    where
+        N: FromStr,
+        N::Err: Display,
+    {
+        let mut rtn = Self::default();
+        for line in input.lines().filter(|line| !line.trim().is_empty()) {
+            let Some((node, neighbors)) = line.split_once(separator) else {
+                return Err(ParseError::new(
+                    input,
+                    line.trim(),
+                    format!("expected `node {separator} neighbours`, found"),
+                ));
+            };
+
+            let from = rtn.add_node(parse_token(input, node.trim())?);
+            for neighbor in neighbors.split(',').map(str::trim) {
+                if !neighbor.is_empty() {
+                    let to = rtn.add_node(parse_token(input, neighbor)?);
+                    rtn.edges[from].push(to);
+                }
+            }
+        }
+        Ok(rtn)
+    }
+
+    /// The id of `label`, adding it when it is new.
+    pub fn add_node(&mut self, label: N) -> usize {
+        if let Some(id) = self.ids.get(&label) {
+            return *id;
+        }
+
+        let id = self.labels.len();
+        self.ids.insert(label.clone(), id);
+        self.labels.push(label);
+        self.edges.push(Vec::new());
+        id
+    }
+
+    pub fn add_edge(&mut self, from: N, to: N) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.edges[from].push(to);
+    }
+
+    pub fn add_undirected_edge(&mut self, a: N, b: N) {
+        self.add_edge(a.clone(), b.clone());
+        self.add_edge(b, a);
+    }
+}
+
+impl<N> Graph<N> {
+    pub fn id(&self, label: &N) -> Option<usize>
+    where
+        N: Eq + Hash,
+    {
+        self.ids.get(label).copied()
+    }
+
+    pub fn label(&self, id: usize) -> &N {
+        &self.labels[id]
+    }
+
+    pub fn labels(&self) -> &[N] {
+        &self.labels
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    pub fn neighbors(&self, id: usize) -> &[usize] {
+        &self.edges[id]
+    }
+
+    /// The ids reachable from `start` in breadth first order, `start` first.
+    pub fn bfs(&self, start: usize) -> Bfs<'_, N> {
+        let mut seen = vec![false; self.len()];
+        seen[start] = true;
+        Bfs {
+            graph: self,
+            queue: VecDeque::from([start]),
+            seen,
+        }
+    }
+
+    /// The ids reachable from `start` in depth first preorder, `start` first.
+    pub fn dfs(&self, start: usize) -> Dfs<'_, N> {
+        Dfs {
+            graph: self,
+            stack: vec![start],
+            seen: vec![false; self.len()],
+        }
+    }
+
+    pub fn is_reachable(&self, from: usize, to: usize) -> bool {
+        self.dfs(from).any(|id| id == to)
+    }
+
+    /// Every id reachable from `start`, sorted.
+    pub fn component_of(&self, start: usize) -> Vec<usize> {
+        let mut rtn: Vec<usize> = self.dfs(start).collect();
+        rtn.sort_unstable();
+        rtn
+    }
+
+    /// Splits the ids into groups by following the edges from each id not
+    /// yet in a group, ordered by their smallest id. This gives the
+    /// connected components when every edge goes both ways.
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut grouped = vec![false; self.len()];
+        let mut rtn = Vec::new();
+        for id in 0..self.len() {
+            if grouped[id] {
+                continue;
+            }
+
+            let component: Vec<usize> = self
+                .component_of(id)
+                .into_iter()
+                .filter(|i| !grouped[*i])
+                .collect();
+            for i in component.iter() {
+                grouped[*i] = true;
+            }
+            rtn.push(component);
+        }
+        rtn
+    }
+
+    /// The graph in Graphviz' DOT language.
+    pub fn to_dot(&self) -> String
+    where
+        N: Display,
+    {
+        let mut rtn = String::from("digraph {\n");
+        for (id, label) in self.labels.iter().enumerate() {
+            writeln!(
+                rtn,
+                "    {id} [label=\"{}\"];",
+                label.to_string().escape_default()
+            )
+            .unwrap();
+        }
+        for (from, targets) in self.edges.iter().enumerate() {
+            for to in targets {
+                writeln!(rtn, "    {from} -> {to};").unwrap();
+            }
+        }
+        rtn.push('}');
+        rtn
+    }
+}
+
+pub struct Bfs<'a, N> {
+    graph: &'a Graph<N>,
+    queue: VecDeque<usize>,
+    seen: Vec<bool>,
+}
+
+impl<N> Iterator for Bfs<'_, N> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let id = self.queue.pop_front()?;
+        for neighbor in self.graph.neighbors(id) {
+            if !self.seen[*neighbor] {
+                self.seen[*neighbor] = true;
+                self.queue.push_back(*neighbor);
+            }
+        }
+        Some(id)
+    }
+}
+
+pub struct Dfs<'a, N> {
+    graph: &'a Graph<N>,
+    stack: Vec<usize>,
+    seen: Vec<bool>,
+}
+
+impl<N> Iterator for Dfs<'_, N> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while let Some(id) = self.stack.pop() {
+            if self.seen[id] {
+                continue;
+            }
+
+            self.seen[id] = true;
+            self.stack.extend(
+                self.graph
+                    .neighbors(id)
+                    .iter()
+                    .rev()
+                    .filter(|i| !self.seen[**i]),
+            );
+            return Some(id);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod graph_test {
+    use crate::graph::Graph;
+
+    const PIPES: &str =
+        "0 <-> 2\n1 <-> 1\n2 <-> 0, 3, 4\n3 <-> 2, 4\n4 <-> 2, 3, 6\n5 <-> 6\n6 <-> 4, 5\n";
+
+    fn labels(graph: &Graph<u32>, ids: impl Iterator<Item = usize>) -> Vec<u32> {
+        ids.map(|id| *graph.label(id)).collect()
+    }
+
+    #[test]
+    fn test_parse_adjacency() {
+        let graph: Graph<u32> = Graph::parse_adjacency(PIPES, "<->").unwrap();
+        assert_eq!(graph.len(), 7);
+
+        let zero = graph.id(&0).unwrap();
+        let mut component = labels(&graph, graph.component_of(zero).into_iter());
+        component.sort();
+        assert_eq!(component, vec![0, 2, 3, 4, 5, 6]);
+        assert_eq!(graph.components().len(), 2);
+
+        let error = Graph::<u32>::parse_adjacency("0 <-> 1\n1 <-> x", "<->").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (2, 7, "x")
+        );
+        assert!(Graph::<u32>::parse_adjacency("0 -> 1", "<->").is_err());
+    }
+
+    #[test]
+    fn test_labels() {
+        let mut graph = Graph::default();
+        graph.add_undirected_edge("b", "a");
+        graph.add_edge("c", "a");
+        graph.add_node("d");
+
+        assert_eq!(graph.labels(), &["b", "a", "c", "d"]);
+        assert_eq!(graph.add_node("a"), 1);
+        assert_eq!(graph.id(&"c"), Some(2));
+        assert_eq!(graph.id(&"e"), None);
+        assert!(graph.is_reachable(2, 0));
+        assert!(!graph.is_reachable(0, 2));
+        assert_eq!(graph.components(), vec![vec![0, 1], vec![2], vec![3]]);
+    }
+
+    #[test]
+    fn test_traversal_order() {
+        let mut graph = Graph::default();
+        for (from, to) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (1, 5)] {
+            graph.add_edge(from, to);
+        }
+
+        assert_eq!(labels(&graph, graph.bfs(0)), vec![0, 1, 2, 3, 5, 4]);
+        assert_eq!(labels(&graph, graph.dfs(0)), vec![0, 1, 3, 4, 5, 2]);
+        assert_eq!(labels(&graph, graph.bfs(3)), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let mut graph = Graph::default();
+        graph.add_edge("a", "b\"");
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n    0 [label=\"a\"];\n    1 [label=\"b\\\"\"];\n    0 -> 1;\n}"
+        );
+    }
+}
@@ -1,32 +1,357 @@
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
 use crate::grid_point::GridPoint;
+use crate::parse::ParseError;
 
+/// A rectangular grid stored row by row in one `Vec`. Cells are addressed
+/// with `(x, y)`, `x` being the column.
 #[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Grid<T> {
-    pub data: Vec<Vec<T>>,
+    width: usize,
+    height: usize,
+    data: Vec<T>,
 }
 
 impl<T> Grid<T> {
+    /// A `width`×`height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            data: vec![value; width * height],
+        }
+    }
+
+    /// A grid from its cells row by row, `data` has to hold a multiple of
+    /// `width` cells.
+    pub fn from_vec(width: usize, data: Vec<T>) -> Result<Self, String> {
+        if width == 0 && !data.is_empty() || width > 0 && !data.len().is_multiple_of(width) {
+            return Err(format!(
+                "{} cells can not be split into rows of {width}",
+                data.len()
+            ));
+        }
+
+        Ok(Grid {
+            width,
+            height: data.len().checked_div(width).unwrap_or(0),
+            data,
+        })
+    }
+
+    /// One row per line of `input`, `f` maps each character to its cell.
+    ///
+    /// Panics on rows of different lengths, see `parse_padded`.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        Self::try_parse(input, |c| Ok::<T, Infallible>(f(c)))
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Like `parse`, the errors of `f` and rows of a different length than
+    /// the first one point into `input`.
+    pub fn try_parse<E: Display>(
+        input: &str,
+        mut f: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let mut data = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in input.lines() {
+            let start = data.len();
+            for (i, c) in line.char_indices() {
+                data.push(f(c).map_err(|error| {
+                    ParseError::new(input, &line[i..i + c.len_utf8()], error.to_string())
+                })?);
+            }
+
+            let row_width = data.len() - start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::new(
+                        input,
+                        line,
+                        format!("expected a row of {width} cells, found"),
+                    ));
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            data,
+        })
+    }
+
+    /// Like `parse`, rows shorter than the longest one are filled up with
+    /// `fill`.
+    pub fn parse_padded(input: &str, fill: T, mut f: impl FnMut(char) -> T) -> Self
+    where
+        T: Clone,
+    {
+        let mut rows: Vec<Vec<T>> = input
+            .lines()
+            .map(|line| line.chars().map(&mut f).collect())
+            .collect();
+
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        for row in rows.iter_mut() {
+            row.resize(width, fill.clone());
+        }
+
+        Self::try_from(rows).unwrap()
+    }
+
+    /// Renders one line per row, `f` formats each cell.
+    pub fn display_with<F, D>(&self, f: F) -> GridDisplay<'_, T, F>
+    where
+        F: Fn(&T) -> D,
+        D: Display,
+    {
+        GridDisplay { grid: self, f }
+    }
+
+    /// Mirrors along the main diagonal.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut data = Vec::with_capacity(self.data.len());
+        for x in 0..self.width {
+            for y in 0..self.height {
+                data.push(self[(x, y)].clone());
+            }
+        }
+
+        Grid {
+            width: self.height,
+            height: self.width,
+            data,
+        }
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut data = Vec::with_capacity(self.data.len());
+        for y in 0..self.height {
+            data.extend(self.row(y).iter().rev().cloned());
+        }
+
+        Grid { data, ..*self }
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut data = Vec::with_capacity(self.data.len());
+        for y in (0..self.height).rev() {
+            data.extend_from_slice(self.row(y));
+        }
+
+        Grid { data, ..*self }
+    }
+
+    /// Rotates clockwise by a quarter turn.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transpose().flip_horizontal()
+    }
+
+    /// Rotates counterclockwise by a quarter turn.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transpose().flip_vertical()
+    }
+
+    /// The 8 rotations and reflections of the grid, starting with the grid
+    /// itself. Symmetric grids yield the same variant more than once.
+    pub fn symmetries(&self) -> impl Iterator<Item = Self>
+    where
+        T: Clone,
+    {
+        let mut rotations = Vec::with_capacity(4);
+        let mut current = self.clone();
+        for _ in 0..4 {
+            let next = current.rotate_cw();
+            rotations.push(current);
+            current = next;
+        }
+
+        let flipped: Vec<Self> = rotations
+            .iter()
+            .map(|grid| grid.flip_horizontal())
+            .collect();
+        rotations.into_iter().chain(flipped)
+    }
+
+    /// The smallest of the `symmetries`, the same for every rotation and
+    /// reflection of the grid.
+    pub fn canonical(&self) -> Self
+    where
+        T: Clone + Ord,
+    {
+        self.symmetries().min().unwrap()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
     pub fn get_max_x(&self) -> usize {
-        self.data.iter().map(|i| i.len()).max().unwrap_or(0)
+        self.width
     }
 
     pub fn get_max_y(&self) -> usize {
-        self.data.len()
+        self.height
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Every cell, row by row.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
     }
 
     pub fn get(&self, x: usize, y: usize) -> Option<&T> {
-        self.data.get(y).and_then(|i| i.get(x))
+        if x < self.width && y < self.height {
+            self.data.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.data.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter_with_coords(&self) -> impl Iterator<Item = (GridPoint, &T)> {
+        let width = self.width;
+        self.data.iter().enumerate().map(move |(i, cell)| {
+            (
+                GridPoint {
+                    x: i % width,
+                    y: i / width,
+                },
+                cell,
+            )
+        })
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// The cells of each column, top to bottom.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| (0..self.height).map(move |y| &self[(x, y)]))
+    }
+
+    /// The cells above, left, right and below `point` that are inside the
+    /// grid, in that order.
+    pub fn neighbors4(&self, point: &GridPoint) -> impl Iterator<Item = (GridPoint, &T)> {
+        self.neighbors(point, &[(0, -1), (-1, 0), (1, 0), (0, 1)])
+    }
+
+    /// The up to 8 cells around `point` that are inside the grid, row by row.
+    pub fn neighbors8(&self, point: &GridPoint) -> impl Iterator<Item = (GridPoint, &T)> {
+        self.neighbors(
+            point,
+            &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        )
+    }
+
+    fn neighbors(
+        &self,
+        point: &GridPoint,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (GridPoint, &T)> {
+        let point = *point;
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let x = point.x.checked_add_signed(*dx)?;
+            let y = point.y.checked_add_signed(*dy)?;
+            self.get(x, y).map(|cell| (GridPoint { x, y }, cell))
+        })
+    }
+
+    /// The first position of `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<GridPoint>
+    where
+        T: PartialEq,
+    {
+        self.positions(value).next()
+    }
+
+    /// Every position of `value`, row by row.
+    pub fn positions<'a>(&'a self, value: &'a T) -> impl Iterator<Item = GridPoint> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter_with_coords()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(point, _)| point)
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            data: self.data.iter().map(f).collect(),
+        }
     }
 
     pub fn count_for(&self, value: &T) -> usize
     where
         T: PartialEq,
     {
-        self.data.iter().flatten().filter(|i| *i == value).count()
+        self.data.iter().filter(|i| *i == value).count()
     }
 
     pub fn set(&mut self, x: usize, y: usize, value: T) {
-        self.data[y][x] = value;
+        self[(x, y)] = value;
     }
 
     pub fn get_from_point(&self, point: &GridPoint) -> Option<&T> {
@@ -39,30 +364,481 @@ impl<T> Grid<T> {
 
     pub fn create_sub_grid(&self, x: usize, y: usize, width: usize, height: usize) -> Self
     where
-        T: Copy,
+        T: Clone,
     {
-        let mut new_data: Vec<Vec<T>> = Vec::with_capacity(height);
-        for y in y..y + width {
-            let mut row = Vec::with_capacity(width);
-            for x in x..x + width {
-                let point = self.get(x, y).unwrap();
-                row.push(*point);
-            }
-            new_data.push(row);
+        let mut data = Vec::with_capacity(width * height);
+        for y in y..y + height {
+            data.extend_from_slice(&self.row(y)[x..x + width]);
+        }
+
+        Grid {
+            width,
+            height,
+            data,
         }
-        Grid { data: new_data }
     }
 
     pub fn get_sub_grid(&self, x: usize, y: usize, width: usize, height: usize) -> Grid<&T> {
-        let mut new_data: Vec<Vec<&T>> = Vec::with_capacity(height);
-        for y in y..y + width {
-            let mut row = Vec::with_capacity(width);
-            for x in x..x + width {
-                let point = self.get(x, y).unwrap();
-                row.push(point);
+        let mut data = Vec::with_capacity(width * height);
+        for y in y..y + height {
+            data.extend(&self.row(y)[x..x + width]);
+        }
+
+        Grid {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Splits the grid into `size`×`size` tiles, row by row. Each tile comes
+    /// with its block coordinates, the tile at `(bx, by)` starts at
+    /// `(bx * size, by * size)`.
+    pub fn tiles(
+        &self,
+        size: usize,
+    ) -> Result<impl Iterator<Item = ((usize, usize), Grid<T>)> + '_, String>
+    where
+        T: Clone,
+    {
+        if size == 0 {
+            return Err(String::from("The tile size has to be at least 1"));
+        }
+
+        let (width, height) = (self.width, self.height);
+        if !width.is_multiple_of(size) || !height.is_multiple_of(size) {
+            return Err(format!(
+                "A {width}x{height} grid can not be split into {size}x{size} tiles"
+            ));
+        }
+
+        let blocks_x = width / size;
+        Ok((0..(height / size) * blocks_x).map(move |i| {
+            let (bx, by) = (i % blocks_x, i / blocks_x);
+            (
+                (bx, by),
+                self.create_sub_grid(bx * size, by * size, size, size),
+            )
+        }))
+    }
+
+    /// Joins tiles of the same size back into one grid, the inverse of
+    /// `tiles`. The block coordinates have to cover a rectangle exactly once.
+    pub fn from_tiles(
+        tiles: impl IntoIterator<Item = ((usize, usize), Grid<T>)>,
+    ) -> Result<Self, String> {
+        let tiles: Vec<((usize, usize), Grid<T>)> = tiles.into_iter().collect();
+        let Some((_, first)) = tiles.first() else {
+            return Ok(Grid {
+                width: 0,
+                height: 0,
+                data: Vec::new(),
+            });
+        };
+
+        let (tile_width, tile_height) = (first.width, first.height);
+        if let Some(((bx, by), _)) = tiles
+            .iter()
+            .find(|(_, tile)| tile.width != tile_width || tile.height != tile_height)
+        {
+            return Err(format!(
+                "The tile at ({bx}, {by}) is not {tile_width}x{tile_height} like the first one"
+            ));
+        }
+
+        let blocks_x = tiles.iter().map(|((bx, _), _)| bx + 1).max().unwrap();
+        let blocks_y = tiles.iter().map(|((_, by), _)| by + 1).max().unwrap();
+        if tiles.len() != blocks_x * blocks_y {
+            return Err(format!(
+                "{} tiles can not fill {blocks_x}x{blocks_y} blocks",
+                tiles.len()
+            ));
+        }
+
+        let mut slots: Vec<Option<std::vec::IntoIter<T>>> =
+            (0..tiles.len()).map(|_| None).collect();
+        for ((bx, by), tile) in tiles {
+            let slot = &mut slots[by * blocks_x + bx];
+            if slot.is_some() {
+                return Err(format!("There is more than one tile at ({bx}, {by})"));
+            }
+            *slot = Some(tile.data.into_iter());
+        }
+
+        let mut cells: Vec<std::vec::IntoIter<T>> = slots.into_iter().flatten().collect();
+        let mut data = Vec::with_capacity(cells.iter().map(|cells| cells.len()).sum());
+        for by in 0..blocks_y {
+            for _ in 0..tile_height {
+                for bx in 0..blocks_x {
+                    data.extend(cells[by * blocks_x + bx].by_ref().take(tile_width));
+                }
+            }
+        }
+
+        Ok(Grid {
+            width: blocks_x * tile_width,
+            height: blocks_y * tile_height,
+            data,
+        })
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> usize {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is outside of the {}x{} grid",
+            self.width,
+            self.height
+        );
+        y * self.width + x
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self.data[self.index_of(x, y)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        let index = self.index_of(x, y);
+        &mut self.data[index]
+    }
+}
+
+/// Rows of different lengths are rejected.
+impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
+    type Error = String;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        if let Some((y, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            return Err(format!(
+                "Row {y} has {} cells, the first row has {width}",
+                row.len()
+            ));
+        }
+
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            data: rows.into_iter().flatten().collect(),
+        })
+    }
+}
+
+impl<T> From<Grid<T>> for Vec<Vec<T>> {
+    fn from(grid: Grid<T>) -> Self {
+        let mut cells = grid.data.into_iter();
+        (0..grid.height)
+            .map(|_| cells.by_ref().take(grid.width).collect())
+            .collect()
+    }
+}
+
+pub struct GridDisplay<'a, T, F> {
+    grid: &'a Grid<T>,
+    f: F,
+}
+
+impl<T, F, D> Display for GridDisplay<'_, T, F>
+where
+    F: Fn(&T) -> D,
+    D: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.grid.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.grid.row(y) {
+                write!(f, "{}", (self.f)(cell))?;
             }
-            new_data.push(row);
         }
-        Grid { data: new_data }
+        Ok(())
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::try_parse(s, Ok::<char, Infallible>)
+    }
+}
+
+impl FromStr for Grid<u8> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::try_parse(s, |c| {
+            if c.is_ascii() {
+                Ok(c as u8)
+            } else {
+                Err("expected an ASCII character, found")
+            }
+        })
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.display_with(|c| *c).fmt(f)
+    }
+}
+
+impl Display for Grid<u8> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.display_with(|c| *c as char).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod grid_test {
+    use crate::grid::Grid;
+    use crate::grid_point::GridPoint;
+
+    fn grid(text: &str) -> Grid<char> {
+        text.parse().unwrap()
+    }
+
+    fn rows<T>(grid: Grid<T>) -> Vec<Vec<T>> {
+        grid.into()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("#.\n.#", |c| c == '#');
+        assert_eq!(rows(grid), vec![vec![true, false], vec![false, true]]);
+    }
+
+    #[test]
+    fn test_parse_padded() {
+        let grid = Grid::parse_padded("  |\n-+\n", ' ', |c| c);
+        assert_eq!(rows(grid), vec![vec![' ', ' ', '|'], vec!['-', '+', ' ']]);
+    }
+
+    #[test]
+    fn test_try_parse() {
+        let error = Grid::try_parse("12\n3x", |c| c.to_digit(10).ok_or("not a digit")).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.token, "x");
+
+        let error = "abc\nde\nfgh".parse::<Grid<char>>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "expected a row of 3 cells, found");
+    }
+
+    #[test]
+    fn test_from_str_and_display() {
+        let text = ".#.\n..#\n###";
+        let grid: Grid<u8> = text.parse().unwrap();
+        assert_eq!(grid.row(0), b".#.");
+        assert_eq!(grid.to_string(), text);
+
+        let grid: Grid<char> = text.parse().unwrap();
+        assert_eq!(grid.to_string(), text);
+
+        assert!("é".parse::<Grid<u8>>().is_err());
+    }
+
+    #[test]
+    fn test_flat_storage() {
+        let mut g = grid("abc\ndef");
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g.as_slice(), &['a', 'b', 'c', 'd', 'e', 'f']);
+        assert_eq!(g[(2, 1)], 'f');
+        assert_eq!(g.get(3, 0), None);
+        assert_eq!(g.get(0, 2), None);
+
+        g[(0, 1)] = 'x';
+        g.row_mut(0)[1] = 'y';
+        *g.get_mut(2, 0).unwrap() = 'z';
+        assert_eq!(g.to_string(), "ayz\nxef");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let g = grid("abc\ndef");
+        let _ = g[(3, 0)];
+    }
+
+    #[test]
+    fn test_conversions() {
+        let nested = vec![vec![1, 2], vec![3, 4], vec![5, 6]];
+        let g = Grid::try_from(nested.clone()).unwrap();
+        assert_eq!((g.width(), g.height()), (2, 3));
+        assert_eq!(g[(1, 2)], 6);
+        assert_eq!(rows(g), nested);
+
+        assert!(Grid::try_from(vec![vec![1, 2], vec![3]]).is_err());
+
+        assert_eq!(Grid::from_vec(2, vec![1, 2, 3, 4]).unwrap().height(), 2);
+        assert!(Grid::from_vec(3, vec![1, 2, 3, 4]).is_err());
+        assert_eq!(Grid::new(2, 1, 0), Grid::from_vec(2, vec![0, 0]).unwrap());
+    }
+
+    fn point(x: usize, y: usize) -> GridPoint {
+        GridPoint { x, y }
+    }
+
+    #[test]
+    fn test_iterators() {
+        let g = grid("abc\ndef");
+        let cells: Vec<_> = g.iter_with_coords().collect();
+        assert_eq!(cells[0], (point(0, 0), &'a'));
+        assert_eq!(cells[4], (point(1, 1), &'e'));
+
+        let rows: Vec<String> = g.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["abc", "def"]);
+
+        let columns: Vec<String> = g.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let g = grid("abc\ndef\nghi");
+        let values = |neighbors: Vec<(GridPoint, &char)>| -> String {
+            neighbors.into_iter().map(|(_, c)| *c).collect()
+        };
+
+        assert_eq!(values(g.neighbors4(&point(1, 1)).collect()), "bdfh");
+        assert_eq!(values(g.neighbors4(&point(0, 0)).collect()), "bd");
+        assert_eq!(values(g.neighbors8(&point(1, 1)).collect()), "abcdfghi");
+        assert_eq!(values(g.neighbors8(&point(2, 2)).collect()), "efh");
+        assert_eq!(
+            g.neighbors4(&point(2, 0)).collect::<Vec<_>>(),
+            vec![(point(1, 0), &'b'), (point(2, 1), &'f')]
+        );
+    }
+
+    #[test]
+    fn test_find_and_map() {
+        let g = grid("#..\n.#.\n..#");
+        assert_eq!(g.find(&'#'), Some(point(0, 0)));
+        assert_eq!(g.find(&'x'), None);
+        assert_eq!(
+            g.positions(&'#').collect::<Vec<_>>(),
+            vec![point(0, 0), point(1, 1), point(2, 2)]
+        );
+
+        let numbers = g.map(|c| if *c == '#' { 1 } else { 0 });
+        assert_eq!(numbers.as_slice().iter().sum::<i32>(), 3);
+        assert_eq!((numbers.width(), numbers.height()), (3, 3));
+    }
+
+    #[test]
+    fn test_transforms() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.transpose(), grid("ad\nbe\ncf"));
+        assert_eq!(g.flip_horizontal(), grid("cba\nfed"));
+        assert_eq!(g.flip_vertical(), grid("def\nabc"));
+        assert_eq!(g.rotate_cw(), grid("da\neb\nfc"));
+        assert_eq!(g.rotate_ccw(), grid("cf\nbe\nad"));
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+        assert_eq!(
+            g.rotate_cw().rotate_cw(),
+            g.flip_horizontal().flip_vertical()
+        );
+    }
+
+    #[test]
+    fn test_symmetries() {
+        let g = grid("ab\ncd");
+        let mut variants: Vec<String> = g.symmetries().map(|v| v.to_string()).collect();
+        assert_eq!(variants[0], "ab\ncd");
+        variants.sort();
+        variants.dedup();
+        assert_eq!(variants.len(), 8);
+
+        assert_eq!(grid(".#\n..").symmetries().count(), 8);
+        let mut variants: Vec<_> = grid(".#\n..").symmetries().collect();
+        variants.sort();
+        variants.dedup();
+        assert_eq!(variants.len(), 4);
+    }
+
+    #[test]
+    fn test_canonical() {
+        let g = grid(".#.\n..#\n###");
+        for variant in g.symmetries() {
+            assert_eq!(variant.canonical(), g.canonical());
+        }
+        assert_ne!(grid("#..\n...\n...").canonical(), g.canonical());
+    }
+
+    #[test]
+    fn test_sub_grid() {
+        let g = grid("abcd\nefgh\nijkl");
+        assert_eq!(g.create_sub_grid(1, 0, 2, 3), grid("bc\nfg\njk"));
+        assert_eq!(g.create_sub_grid(0, 1, 3, 1), grid("efg"));
+        assert_eq!(
+            rows(g.get_sub_grid(2, 1, 2, 2)),
+            vec![vec![&'g', &'h'], vec![&'k', &'l']]
+        );
+    }
+
+    #[test]
+    fn test_tiles() {
+        let g = grid("abcd\nefgh\nijkl\nmnop");
+        let tiles: Vec<_> = g.tiles(2).unwrap().collect();
+        assert_eq!(
+            tiles
+                .iter()
+                .map(|(position, _)| *position)
+                .collect::<Vec<_>>(),
+            vec![(0, 0), (1, 0), (0, 1), (1, 1)]
+        );
+        assert_eq!(tiles[1].1, grid("cd\ngh"));
+        assert_eq!(tiles[2].1, grid("ij\nmn"));
+
+        assert!(g.tiles(3).is_err());
+        assert!(g.tiles(0).is_err());
+    }
+
+    #[test]
+    fn test_from_tiles() {
+        let g = grid("abcdef\nghijkl");
+        assert_eq!(Grid::from_tiles(g.tiles(2).unwrap()).unwrap(), g);
+
+        let mut tiles: Vec<_> = g.tiles(2).unwrap().collect();
+        tiles.reverse();
+        assert_eq!(Grid::from_tiles(tiles).unwrap(), g);
+
+        let doubled = Grid::from_tiles(
+            grid("ab")
+                .tiles(1)
+                .unwrap()
+                .map(|(position, tile)| (position, Grid::new(2, 2, tile[(0, 0)]))),
+        )
+        .unwrap();
+        assert_eq!(doubled, grid("aabb\naabb"));
+
+        assert!(Grid::from_tiles(vec![((0, 0), grid("ab")), ((1, 0), grid("a"))]).is_err());
+        assert!(Grid::from_tiles(vec![((0, 0), grid("a")), ((2, 0), grid("b"))]).is_err());
+        let duplicate = vec![
+            ((0, 0), grid("a")),
+            ((0, 0), grid("b")),
+            ((1, 0), grid("c")),
+            ((1, 1), grid("d")),
+        ];
+        assert!(Grid::from_tiles(duplicate).is_err());
+        assert_eq!(
+            Grid::<char>::from_tiles(Vec::new()).unwrap(),
+            Grid::default()
+        );
+    }
+
+    #[test]
+    fn test_display_with() {
+        let grid = Grid::parse("#.\n.#", |c| c == '#');
+        let text = grid
+            .display_with(|on| if *on { "[]" } else { "  " })
+            .to_string();
+        assert_eq!(text, "[]  \n  []");
     }
 }
@@ -1,6 +1,6 @@
-use crate::grid_direction::GridDirection;
+use crate::direction::Direction;
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
 pub struct GridPoint {
     pub x: usize,
     pub y: usize,
@@ -112,61 +112,29 @@ impl GridPoint {
         }
     }
 
-    pub fn move_direction(&mut self, direction: &GridDirection) {
-        match direction {
-            GridDirection::Up => self.move_up(),
-            GridDirection::Right => self.move_right(),
-            GridDirection::Down => self.move_down(),
-            GridDirection::Left => self.move_left(),
-        }
+    pub fn move_direction(&mut self, direction: &Direction) {
+        *self = self.next_by_direction(direction);
     }
 
+    /// `None` when the step leaves `0..max_x` or `0..max_y`.
     pub fn next_by_direction_with_check(
         &self,
-        direction: &GridDirection,
+        direction: &Direction,
         max_x: usize,
         max_y: usize,
     ) -> Option<Self> {
-        match direction {
-            GridDirection::Up => self.next_up_checked(),
-            GridDirection::Right => self.next_right_checked(max_x),
-            GridDirection::Down => self.next_down_checked(max_y),
-            GridDirection::Left => self.next_left_checked(),
-        }
-    }
-
-    pub fn next_by_direction(&self, direction: &GridDirection) -> Self {
-        match direction {
-            GridDirection::Up => self.next_up(),
-            GridDirection::Right => self.next_right(),
-            GridDirection::Down => self.next_down(),
-            GridDirection::Left => self.next_left(),
-        }
-    }
-
-    fn next_up_checked(&self) -> Option<GridPoint> {
-        if self.y != 0 {
-            return Some(self.next_up());
-        }
-        None
-    }
-    fn next_right_checked(&self, max_x: usize) -> Option<GridPoint> {
-        if self.x != max_x - 1 {
-            return Some(self.next_right());
+        let delta = direction.grid_delta();
+        let x = self.x.checked_add_signed(delta.x() as isize)?;
+        let y = self.y.checked_add_signed(delta.y() as isize)?;
+        if x < max_x && y < max_y {
+            Some(Self { x, y })
+        } else {
+            None
         }
-        None
-    }
-    fn next_down_checked(&self, max_y: usize) -> Option<GridPoint> {
-        if self.y != max_y - 1 {
-            return Some(self.next_down());
-        }
-        None
     }
 
-    fn next_left_checked(&self) -> Option<GridPoint> {
-        if self.x != 0 {
-            return Some(self.next_left());
-        }
-        None
+    pub fn next_by_direction(&self, direction: &Direction) -> Self {
+        self.next_by_direction_with_check(direction, usize::MAX, usize::MAX)
+            .unwrap_or_else(|| panic!("Moving {direction} from {self:?} leaves the grid"))
     }
 }
@@ -0,0 +1,160 @@
+use crate::direction::Direction;
+use crate::grid::Grid;
+use crate::grid_point::GridPoint;
+
+/// Walks a `Grid` without leaving it. The cells it has stood on are kept in
+/// a grid of its own, so the walked grid does not have to be changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridWalker {
+    pub direction: Direction,
+    position: GridPoint,
+    visited: Grid<bool>,
+}
+
+impl GridWalker {
+    /// A walker for grids the size of `grid`, standing on `position`.
+    pub fn new<T>(grid: &Grid<T>, position: GridPoint, direction: Direction) -> Self {
+        let mut visited = Grid::new(grid.width(), grid.height(), false);
+        visited.set_from_point(&position, true);
+
+        Self {
+            direction,
+            position,
+            visited,
+        }
+    }
+
+    pub fn position(&self) -> GridPoint {
+        self.position
+    }
+
+    pub fn visited(&self) -> &Grid<bool> {
+        &self.visited
+    }
+
+    pub fn has_visited(&self, point: &GridPoint) -> bool {
+        self.visited.get_from_point(point) == Some(&true)
+    }
+
+    pub fn visited_count(&self) -> usize {
+        self.visited.count_for(&true)
+    }
+
+    pub fn turn_left(&mut self) {
+        self.direction = self.direction.turn_left();
+    }
+
+    pub fn turn_right(&mut self) {
+        self.direction = self.direction.turn_right();
+    }
+
+    pub fn turn_around(&mut self) {
+        self.direction = self.direction.opposite();
+    }
+
+    /// The cell one step in `direction`, `None` outside of the grid.
+    pub fn next_in(&self, direction: &Direction) -> Option<GridPoint> {
+        self.position.next_by_direction_with_check(
+            direction,
+            self.visited.width(),
+            self.visited.height(),
+        )
+    }
+
+    pub fn ahead(&self) -> Option<GridPoint> {
+        self.next_in(&self.direction)
+    }
+
+    pub fn to_left(&self) -> Option<GridPoint> {
+        self.next_in(&self.direction.turn_left())
+    }
+
+    pub fn to_right(&self) -> Option<GridPoint> {
+        self.next_in(&self.direction.turn_right())
+    }
+
+    pub fn peek<'a, T>(&self, grid: &'a Grid<T>) -> Option<&'a T> {
+        self.ahead().and_then(|point| grid.get_from_point(&point))
+    }
+
+    pub fn peek_left<'a, T>(&self, grid: &'a Grid<T>) -> Option<&'a T> {
+        self.to_left().and_then(|point| grid.get_from_point(&point))
+    }
+
+    pub fn peek_right<'a, T>(&self, grid: &'a Grid<T>) -> Option<&'a T> {
+        self.to_right()
+            .and_then(|point| grid.get_from_point(&point))
+    }
+
+    /// Steps forward and marks the new cell as visited. At the edge of the
+    /// grid it stays put and returns `false`.
+    pub fn r#move(&mut self) -> bool {
+        match self.ahead() {
+            Some(point) => {
+                self.position = point;
+                self.visited.set_from_point(&point, true);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod grid_walker_test {
+    use crate::direction::Direction;
+    use crate::grid::Grid;
+    use crate::grid_point::GridPoint;
+    use crate::grid_walker::GridWalker;
+
+    fn point(x: usize, y: usize) -> GridPoint {
+        GridPoint { x, y }
+    }
+
+    #[test]
+    fn test_move_stays_in_grid() {
+        let grid = Grid::new(3, 2, 0);
+        let mut walker = GridWalker::new(&grid, point(0, 0), Direction::Right);
+
+        assert!(walker.r#move());
+        assert!(walker.r#move());
+        assert!(!walker.r#move());
+        assert_eq!(walker.position(), point(2, 0));
+
+        walker.turn_right();
+        assert_eq!(walker.direction, Direction::Down);
+        assert!(walker.r#move());
+        assert!(!walker.r#move());
+        assert_eq!(walker.position(), point(2, 1));
+
+        walker.turn_around();
+        assert_eq!(walker.ahead(), Some(point(2, 0)));
+        assert_eq!(walker.to_left(), Some(point(1, 1)));
+        assert_eq!(walker.to_right(), None);
+        assert_eq!(walker.visited_count(), 4);
+        assert!(walker.has_visited(&point(1, 0)));
+        assert!(!walker.has_visited(&point(1, 1)));
+        assert!(!walker.has_visited(&point(7, 7)));
+    }
+
+    #[test]
+    fn test_peek() {
+        let grid: Grid<char> = "abc\ndef\nghi".parse().unwrap();
+        let mut walker = GridWalker::new(&grid, point(1, 1), Direction::Up);
+
+        assert_eq!(walker.peek(&grid), Some(&'b'));
+        assert_eq!(walker.peek_left(&grid), Some(&'d'));
+        assert_eq!(walker.peek_right(&grid), Some(&'f'));
+
+        walker.turn_left();
+        assert_eq!(walker.peek(&grid), Some(&'d'));
+        assert_eq!(walker.peek_left(&grid), Some(&'h'));
+        assert_eq!(walker.peek_right(&grid), Some(&'b'));
+
+        walker.r#move();
+        assert_eq!(walker.peek(&grid), None);
+        assert_eq!(walker.to_left(), Some(point(0, 2)));
+        assert_eq!(walker.peek_right(&grid), Some(&'a'));
+        assert_eq!(grid.to_string(), "abc\ndef\nghi");
+    }
+}
@@ -2,9 +2,27 @@ use std::collections::HashMap;
 
 use crate::point::MapPoint;
 
-#[derive(Debug, Default)]
+/// How `HashPointMap::render` lays out the y axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YAxis {
+    /// The largest y is the first line, like `MapPoint::move_up`.
+    Up,
+    /// The smallest y is the first line, like screen coordinates.
+    Down,
+}
+
+/// A sparse map of points.
+#[derive(Debug, Clone)]
 pub struct HashPointMap<T> {
-    pub data: HashMap<MapPoint, T>,
+    data: HashMap<MapPoint, T>,
+}
+
+impl<T> Default for HashPointMap<T> {
+    fn default() -> Self {
+        Self {
+            data: HashMap::new(),
+        }
+    }
 }
 
 impl<T> HashPointMap<T> {
@@ -12,6 +30,18 @@ impl<T> HashPointMap<T> {
         self.data.get(point)
     }
 
+    pub fn get_mut(&mut self, point: &MapPoint) -> Option<&mut T> {
+        self.data.get_mut(point)
+    }
+
+    /// The value at `point`, `T::default()` when there is none.
+    pub fn get_or_default(&self, point: &MapPoint) -> T
+    where
+        T: Clone + Default,
+    {
+        self.data.get(point).cloned().unwrap_or_default()
+    }
+
     pub fn push(&mut self, key: MapPoint, value: T) {
         self.data.insert(key, value);
     }
@@ -19,4 +49,176 @@ impl<T> HashPointMap<T> {
     pub fn remove(&mut self, point: &MapPoint) -> Option<T> {
         self.data.remove(point)
     }
+
+    pub fn entry(&mut self, point: MapPoint) -> Entry<'_, T> {
+        Entry { map: self, point }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&MapPoint, &T)> {
+        self.data.iter()
+    }
+
+    pub fn count_where(&self, mut f: impl FnMut(&T) -> bool) -> usize {
+        self.data.values().filter(|value| f(value)).count()
+    }
+
+    /// The smallest and the largest x and y of all points, both inclusive.
+    /// Scans every point, so inserting and removing stay cheap.
+    pub fn bounds(&self) -> Option<(MapPoint, MapPoint)> {
+        let mut points = self.data.keys();
+        let first = *points.next()?;
+
+        Some(points.fold((first, first), |(min, max), point| {
+            (
+                MapPoint::new(min.x.min(point.x), min.y.min(point.y)),
+                MapPoint::new(max.x.max(point.x), max.y.max(point.y)),
+            )
+        }))
+    }
+
+    /// Draws the region spanned by `bounds`, one line per row, `f` picks the
+    /// character of each point. An empty map renders as an empty string.
+    pub fn render(&self, f: impl Fn(Option<&T>) -> char, y_axis: YAxis) -> String {
+        let Some((min, max)) = self.bounds() else {
+            return String::new();
+        };
+
+        let rows: Vec<i64> = match y_axis {
+            YAxis::Up => (min.y..=max.y).rev().collect(),
+            YAxis::Down => (min.y..=max.y).collect(),
+        };
+
+        rows.into_iter()
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| f(self.data.get(&MapPoint::new(x, y))))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// A point of a `HashPointMap` that may or may not hold a value yet.
+pub struct Entry<'a, T> {
+    map: &'a mut HashPointMap<T>,
+    point: MapPoint,
+}
+
+impl<'a, T> Entry<'a, T> {
+    pub fn key(&self) -> &MapPoint {
+        &self.point
+    }
+
+    pub fn and_modify(self, f: impl FnOnce(&mut T)) -> Self {
+        if let Some(value) = self.map.data.get_mut(&self.point) {
+            f(value);
+        }
+        self
+    }
+
+    pub fn or_insert_with(self, f: impl FnOnce() -> T) -> &'a mut T {
+        self.map.data.entry(self.point).or_insert_with(f)
+    }
+
+    pub fn or_insert(self, value: T) -> &'a mut T {
+        self.or_insert_with(|| value)
+    }
+
+    pub fn or_default(self) -> &'a mut T
+    where
+        T: Default,
+    {
+        self.or_insert_with(T::default)
+    }
+}
+
+#[cfg(test)]
+mod hash_point_map_test {
+    use crate::hash_point_map::{HashPointMap, YAxis};
+    use crate::point::MapPoint;
+
+    #[test]
+    fn test_bounds() {
+        let mut map = HashPointMap::default();
+        assert_eq!(map.bounds(), None);
+
+        map.push(MapPoint::new(1, 1), 'a');
+        map.push(MapPoint::new(-2, 3), 'b');
+        map.push(MapPoint::new(4, -1), 'c');
+        assert_eq!(
+            map.bounds(),
+            Some((MapPoint::new(-2, -1), MapPoint::new(4, 3)))
+        );
+
+        map.push(MapPoint::new(4, -1), 'd');
+        map.remove(&MapPoint::new(4, -1));
+        assert_eq!(
+            map.bounds(),
+            Some((MapPoint::new(-2, 1), MapPoint::new(1, 3)))
+        );
+
+        map.remove(&MapPoint::new(-2, 3));
+        map.remove(&MapPoint::new(7, 7));
+        assert_eq!(
+            map.bounds(),
+            Some((MapPoint::new(1, 1), MapPoint::new(1, 1)))
+        );
+
+        map.remove(&MapPoint::new(1, 1));
+        assert_eq!(map.bounds(), None);
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn test_entry() {
+        let mut map: HashPointMap<i32> = HashPointMap::default();
+        *map.entry(MapPoint::new(0, 0)).or_default() += 2;
+        map.entry(MapPoint::new(0, 0))
+            .and_modify(|v| *v *= 10)
+            .or_insert(1);
+        map.entry(MapPoint::new(5, -5))
+            .and_modify(|v| *v *= 10)
+            .or_insert(1);
+
+        assert_eq!(map.get(&MapPoint::new(0, 0)), Some(&20));
+        assert_eq!(map.get_or_default(&MapPoint::new(5, -5)), 1);
+        assert_eq!(map.get_or_default(&MapPoint::new(1, 1)), 0);
+        assert_eq!(
+            map.bounds(),
+            Some((MapPoint::new(0, -5), MapPoint::new(5, 0)))
+        );
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn test_iter_and_count_where() {
+        let mut map = HashPointMap::default();
+        for x in 0..5 {
+            map.push(MapPoint::new(x, 0), x);
+        }
+
+        assert_eq!(map.iter().map(|(_, v)| v).sum::<i64>(), 10);
+        assert_eq!(map.count_where(|v| v % 2 == 0), 3);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut map = HashPointMap::default();
+        map.push(MapPoint::new(0, 0), '#');
+        map.push(MapPoint::new(2, 1), '#');
+
+        let f = |c: Option<&char>| c.copied().unwrap_or('.');
+        assert_eq!(map.render(f, YAxis::Up), "..#\n#..");
+        assert_eq!(map.render(f, YAxis::Down), "#..\n..#");
+        assert_eq!(HashPointMap::<char>::default().render(f, YAxis::Up), "");
+    }
 }
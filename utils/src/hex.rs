@@ -0,0 +1,436 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+use std::str::FromStr;
+
+use crate::parse::{parse_within, ParseError};
+
+/// A hex in cube coordinates, `q + r + s` is always zero. `r` grows towards
+/// the bottom of the screen, `q` towards the right.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Cube {
+    q: i64,
+    r: i64,
+    s: i64,
+}
+
+/// A hex in axial coordinates, cube coordinates without the redundant `s`.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Axial {
+    pub q: i64,
+    pub r: i64,
+}
+
+/// The steps to the six neighbours, clockwise from the top of a flat-topped
+/// hex. Pointy-topped hexes use the same steps, starting one later.
+const DELTAS: [Cube; 6] = [
+    Cube { q: 0, r: -1, s: 1 },
+    Cube { q: 1, r: -1, s: 0 },
+    Cube { q: 1, r: 0, s: -1 },
+    Cube { q: 0, r: 1, s: -1 },
+    Cube { q: -1, r: 1, s: 0 },
+    Cube { q: -1, r: 0, s: 1 },
+];
+
+impl Cube {
+    pub fn new(q: i64, r: i64) -> Self {
+        Self { q, r, s: -q - r }
+    }
+
+    pub fn q(&self) -> i64 {
+        self.q
+    }
+
+    pub fn r(&self) -> i64 {
+        self.r
+    }
+
+    pub fn s(&self) -> i64 {
+        self.s
+    }
+
+    pub fn neighbor(&self, direction: impl HexDirection) -> Self {
+        *self + direction.delta()
+    }
+
+    pub fn neighbors(&self) -> [Cube; 6] {
+        DELTAS.map(|delta| *self + delta)
+    }
+
+    /// The number of steps from the origin.
+    pub fn length(&self) -> i64 {
+        (self.q.abs() + self.r.abs() + self.s.abs()) / 2
+    }
+
+    pub fn distance(&self, other: &Self) -> i64 {
+        (*self - *other).length()
+    }
+
+    /// Turns around the origin by `steps` sixths of a full turn, clockwise
+    /// for positive steps.
+    pub fn rotate(&self, steps: i64) -> Self {
+        (0..steps.rem_euclid(6)).fold(*self, |hex, _| Self {
+            q: -hex.r,
+            r: -hex.s,
+            s: -hex.q,
+        })
+    }
+
+    pub fn rotate_around(&self, center: &Self, steps: i64) -> Self {
+        (*self - *center).rotate(steps) + *center
+    }
+
+    /// The `6 * radius` hexes at exactly `radius` steps, just `self` for a
+    /// radius of zero.
+    pub fn ring(&self, radius: i64) -> Vec<Cube> {
+        if radius <= 0 {
+            return vec![*self];
+        }
+
+        let mut rtn = Vec::with_capacity(6 * radius as usize);
+        let mut hex = *self + DELTAS[4] * radius;
+        for delta in DELTAS {
+            for _ in 0..radius {
+                rtn.push(hex);
+                hex += delta;
+            }
+        }
+        rtn
+    }
+
+    /// Every hex at most `radius` steps away, ring by ring from `self`.
+    pub fn spiral(&self, radius: i64) -> Vec<Cube> {
+        (0..=radius).flat_map(|r| self.ring(r)).collect()
+    }
+
+    /// The hexes on the straight line to `other`, both ends included.
+    pub fn line_to(&self, other: &Self) -> Vec<Cube> {
+        let n = self.distance(other);
+        if n == 0 {
+            return vec![*self];
+        }
+
+        // Nudged off the edges between hexes, so that rounding is stable.
+        let (q, r, s) = (
+            self.q as f64 + 1e-6,
+            self.r as f64 + 2e-6,
+            self.s as f64 - 3e-6,
+        );
+        (0..=n)
+            .map(|i| {
+                let t = i as f64 / n as f64;
+                Self::round(
+                    q + (other.q as f64 - q) * t,
+                    r + (other.r as f64 - r) * t,
+                    s + (other.s as f64 - s) * t,
+                )
+            })
+            .collect()
+    }
+
+    /// The hex that contains the fractional cube coordinates.
+    pub fn round(q: f64, r: f64, s: f64) -> Self {
+        let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+
+        if dq > dr && dq > ds {
+            rq = -rr - rs;
+        } else if dr > ds {
+            rr = -rq - rs;
+        }
+        Self::new(rq as i64, rr as i64)
+    }
+}
+
+impl Add for Cube {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl AddAssign for Cube {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Cube {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Mul<i64> for Cube {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self {
+        Self::new(self.q * factor, self.r * factor)
+    }
+}
+
+impl Neg for Cube {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.q, -self.r)
+    }
+}
+
+impl From<Axial> for Cube {
+    fn from(axial: Axial) -> Self {
+        Self::new(axial.q, axial.r)
+    }
+}
+
+impl From<Cube> for Axial {
+    fn from(cube: Cube) -> Self {
+        Axial {
+            q: cube.q,
+            r: cube.r,
+        }
+    }
+}
+
+/// The six directions of one orientation of hexes, clockwise.
+pub trait HexDirection: Copy + Sized + 'static {
+    const ALL: [Self; 6];
+
+    /// The position in `ALL`.
+    fn index(&self) -> usize;
+
+    /// The step in cube coordinates.
+    fn delta(&self) -> Cube;
+
+    /// Turns by `steps` sixths of a full turn, clockwise for positive steps.
+    fn rotate(&self, steps: i64) -> Self {
+        Self::ALL[(self.index() as i64 + steps).rem_euclid(6) as usize]
+    }
+
+    fn opposite(&self) -> Self {
+        self.rotate(3)
+    }
+}
+
+/// Directions between flat-topped hexes, these have no east or west.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FlatDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+/// Directions between pointy-topped hexes, these have no north or south.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PointyDirection {
+    NorthEast,
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl HexDirection for FlatDirection {
+    const ALL: [Self; 6] = [
+        FlatDirection::North,
+        FlatDirection::NorthEast,
+        FlatDirection::SouthEast,
+        FlatDirection::South,
+        FlatDirection::SouthWest,
+        FlatDirection::NorthWest,
+    ];
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+
+    fn delta(&self) -> Cube {
+        DELTAS[self.index()]
+    }
+}
+
+impl HexDirection for PointyDirection {
+    const ALL: [Self; 6] = [
+        PointyDirection::NorthEast,
+        PointyDirection::East,
+        PointyDirection::SouthEast,
+        PointyDirection::SouthWest,
+        PointyDirection::West,
+        PointyDirection::NorthWest,
+    ];
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+
+    fn delta(&self) -> Cube {
+        DELTAS[(self.index() + 1) % 6]
+    }
+}
+
+/// Compass names like `n` or `SE`, in either case.
+impl FromStr for FlatDirection {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let token = s.trim();
+        match token.to_ascii_lowercase().as_str() {
+            "n" => Ok(FlatDirection::North),
+            "ne" => Ok(FlatDirection::NorthEast),
+            "se" => Ok(FlatDirection::SouthEast),
+            "s" => Ok(FlatDirection::South),
+            "sw" => Ok(FlatDirection::SouthWest),
+            "nw" => Ok(FlatDirection::NorthWest),
+            _ => Err(ParseError::new(
+                s,
+                token,
+                "expected one of n, ne, se, s, sw, nw, found",
+            )),
+        }
+    }
+}
+
+/// Compass names like `e` or `NW`, in either case.
+impl FromStr for PointyDirection {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let token = s.trim();
+        match token.to_ascii_lowercase().as_str() {
+            "ne" => Ok(PointyDirection::NorthEast),
+            "e" => Ok(PointyDirection::East),
+            "se" => Ok(PointyDirection::SouthEast),
+            "sw" => Ok(PointyDirection::SouthWest),
+            "w" => Ok(PointyDirection::West),
+            "nw" => Ok(PointyDirection::NorthWest),
+            _ => Err(ParseError::new(
+                s,
+                token,
+                "expected one of ne, e, se, sw, w, nw, found",
+            )),
+        }
+    }
+}
+
+/// Parses a comma separated list of steps like `ne,ne,s,s`.
+pub fn parse_steps<D>(input: &str) -> Result<Vec<D>, ParseError>
+where
+    D: HexDirection + FromStr<Err = ParseError>,
+{
+    input
+        .trim()
+        .split(',')
+        .map(|token| parse_within(input, token))
+        .collect()
+}
+
+#[cfg(test)]
+mod hex_test {
+    use std::collections::HashSet;
+
+    use crate::hex::{parse_steps, Axial, Cube, FlatDirection, HexDirection, PointyDirection};
+
+    fn walk<D: HexDirection>(steps: &[D]) -> Cube {
+        steps
+            .iter()
+            .fold(Cube::default(), |hex, step| hex.neighbor(*step))
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(FlatDirection::North.delta(), Cube::new(0, -1));
+        assert_eq!(FlatDirection::SouthEast.delta(), Cube::new(1, 0));
+        assert_eq!(PointyDirection::East.delta(), Cube::new(1, 0));
+        assert_eq!(PointyDirection::NorthWest.delta(), Cube::new(0, -1));
+
+        for direction in FlatDirection::ALL {
+            assert_eq!(direction.opposite().delta(), -direction.delta());
+            assert_eq!(direction.rotate(1).delta(), direction.delta().rotate(1));
+            assert_eq!(direction.delta().length(), 1);
+        }
+        for direction in PointyDirection::ALL {
+            assert_eq!(direction.opposite().delta(), -direction.delta());
+            assert_eq!(direction.rotate(-1).delta(), direction.delta().rotate(-1));
+        }
+    }
+
+    #[test]
+    fn test_parse_and_distance() {
+        let distance = |input: &str| walk(&parse_steps::<FlatDirection>(input).unwrap()).length();
+        assert_eq!(distance("ne,ne,ne"), 3);
+        assert_eq!(distance("ne,ne,sw,sw"), 0);
+        assert_eq!(distance("ne,ne,s,s"), 2);
+        assert_eq!(distance("se,sw,se,sw,sw\n"), 3);
+        assert_eq!(distance("N,NW,nw"), 3);
+
+        let steps: Vec<PointyDirection> = parse_steps("e,w,nw,se").unwrap();
+        assert_eq!(walk(&steps), Cube::default());
+
+        let error = parse_steps::<FlatDirection>("ne,e,s").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+        assert!(parse_steps::<PointyDirection>("n").is_err());
+    }
+
+    #[test]
+    fn test_rings_and_spirals() {
+        let center = Cube::new(2, -5);
+        assert_eq!(center.ring(0), vec![center]);
+
+        for radius in 1..5 {
+            let ring = center.ring(radius);
+            assert_eq!(ring.len(), 6 * radius as usize);
+            assert!(ring.iter().all(|hex| hex.distance(&center) == radius));
+            assert_eq!(ring.iter().collect::<HashSet<_>>().len(), ring.len());
+            assert!(ring.windows(2).all(|w| w[0].distance(&w[1]) == 1));
+        }
+
+        let spiral = center.spiral(3);
+        assert_eq!(spiral.len(), 1 + 6 + 12 + 18);
+        assert_eq!(spiral.iter().collect::<HashSet<_>>().len(), spiral.len());
+        assert_eq!(
+            center.neighbors().iter().collect::<HashSet<_>>(),
+            center.ring(1).iter().collect::<HashSet<_>>()
+        );
+    }
+
+    #[test]
+    fn test_line() {
+        let start = Cube::new(0, 0);
+        let end = Cube::new(3, -1);
+        let line = start.line_to(&end);
+
+        assert_eq!(line.len(), 4);
+        assert_eq!((line[0], line[3]), (start, end));
+        assert!(line.windows(2).all(|w| w[0].distance(&w[1]) == 1));
+        assert_eq!(start.line_to(&start), vec![start]);
+
+        let straight = Cube::new(-2, 2).line_to(&Cube::new(2, -2));
+        assert!(straight.iter().all(|hex| hex.s() == 0));
+    }
+
+    #[test]
+    fn test_rotate_and_axial() {
+        let hex = Cube::new(2, -1);
+        assert_eq!(hex.rotate(1), Cube::new(1, 1));
+        assert_eq!(hex.rotate(-1), hex.rotate(5));
+        assert_eq!(hex.rotate(6), hex);
+        assert_eq!(hex.rotate(3), -hex);
+
+        let center = Cube::new(5, 5);
+        assert_eq!(center.rotate_around(&center, 2), center);
+        assert_eq!(
+            (center + hex).rotate_around(&center, 1),
+            center + hex.rotate(1)
+        );
+
+        let axial = Axial { q: 3, r: -7 };
+        let cube = Cube::from(axial);
+        assert_eq!(cube.q() + cube.r() + cube.s(), 0);
+        assert_eq!(Axial::from(cube), axial);
+    }
+}
@@ -0,0 +1,239 @@
+use std::env::current_dir;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::grid::Grid;
+use crate::parse::{parse_token, ParseError};
+
+pub const INPUT: &str = "input.txt";
+pub const INPUT_TEST: &str = "input_test.txt";
+pub const INPUT_TEST_2: &str = "input_test_2.txt";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputError {
+    NotFound {
+        name: String,
+        searched: Vec<PathBuf>,
+    },
+    Read {
+        path: PathBuf,
+        message: String,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound { name, searched } => {
+                let searched: Vec<String> =
+                    searched.iter().map(|p| p.display().to_string()).collect();
+                write!(f, "Could not find {name}, searched {}", searched.join(", "))
+            }
+            InputError::Read { path, message } => {
+                write!(f, "Could not read {}: {message}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// The text of a puzzle input with helpers for the usual layouts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Input {
+    path: Option<PathBuf>,
+    text: String,
+}
+
+impl Input {
+    /// Finds `name` in the `input` folder of the crate `src_path` belongs to.
+    /// Pass `file!()` as `src_path`; when it is not reachable from the current
+    /// directory, as in tests, `./input` is searched instead.
+    pub fn locate(src_path: &str, name: &str) -> Result<PathBuf, InputError> {
+        let mut searched = Vec::new();
+
+        if let Some(crate_dir) = Path::new(src_path).ancestors().nth(2) {
+            if Path::new(src_path).exists() {
+                searched.push(crate_dir.join("input").join(name));
+            }
+        }
+        if let Ok(dir) = current_dir() {
+            searched.push(dir.join("input").join(name));
+        }
+
+        match searched.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(path.clone()),
+            None => Err(InputError::NotFound {
+                name: name.to_string(),
+                searched,
+            }),
+        }
+    }
+
+    pub fn open(src_path: &str, name: &str) -> Result<Self, InputError> {
+        Self::from_path(Self::locate(src_path, name)?)
+    }
+
+    pub fn puzzle(src_path: &str) -> Result<Self, InputError> {
+        Self::open(src_path, INPUT)
+    }
+
+    pub fn test(src_path: &str) -> Result<Self, InputError> {
+        Self::open(src_path, INPUT_TEST)
+    }
+
+    pub fn test_2(src_path: &str) -> Result<Self, InputError> {
+        Self::open(src_path, INPUT_TEST_2)
+    }
+
+    pub fn from_path(path: impl Into<PathBuf>) -> Result<Self, InputError> {
+        let path = path.into();
+        let text = fs::read_to_string(&path).map_err(|error| InputError::Read {
+            path: path.clone(),
+            message: error.to_string(),
+        })?;
+
+        Ok(Self {
+            path: Some(path),
+            text,
+        })
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text.lines()
+    }
+
+    /// Every number separated by whitespace or commas.
+    pub fn ints<T: FromStr>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T::Err: Display,
+    {
+        self.text
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty())
+            .map(|token| parse_token(&self.text, token))
+            .collect()
+    }
+
+    /// One row per line, one cell per character.
+    pub fn grid<T: TryFrom<char>>(&self) -> Result<Grid<T>, ParseError>
+    where
+        T::Error: Display,
+    {
+        Grid::try_parse(&self.text, T::try_from)
+    }
+
+    /// The blocks of text between empty lines.
+    pub fn split_blank_lines(&self) -> Vec<&str> {
+        let mut rtn = Vec::new();
+        let mut start = None;
+        let mut end = 0;
+        let mut offset = 0;
+
+        for line in self.text.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\n', '\r']);
+            if content.trim().is_empty() {
+                if let Some(block_start) = start.take() {
+                    rtn.push(&self.text[block_start..end]);
+                }
+            } else {
+                start.get_or_insert(offset);
+                end = offset + content.len();
+            }
+            offset += line.len();
+        }
+        if let Some(block_start) = start {
+            rtn.push(&self.text[block_start..end]);
+        }
+
+        rtn
+    }
+}
+
+impl From<&str> for Input {
+    fn from(value: &str) -> Self {
+        Self {
+            path: None,
+            text: value.to_string(),
+        }
+    }
+}
+
+impl From<String> for Input {
+    fn from(value: String) -> Self {
+        Self {
+            path: None,
+            text: value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod input_test {
+    use std::env::temp_dir;
+    use std::fs;
+    use std::process;
+
+    use crate::input::{Input, InputError, INPUT_TEST};
+
+    #[test]
+    fn test_open() {
+        let crate_dir = temp_dir().join(format!("utils_input_test_{}", process::id()));
+        fs::create_dir_all(crate_dir.join("src")).unwrap();
+        fs::create_dir_all(crate_dir.join("input")).unwrap();
+        fs::write(crate_dir.join("src").join("lib.rs"), "").unwrap();
+        fs::write(crate_dir.join("input").join(INPUT_TEST), "1\n2\n").unwrap();
+        let src_path = crate_dir.join("src").join("lib.rs");
+        let src_path = src_path.to_str().unwrap();
+
+        let input = Input::test(src_path).unwrap();
+        assert_eq!(input.text(), "1\n2\n");
+        assert_eq!(input.lines().collect::<Vec<_>>(), vec!["1", "2"]);
+
+        let error = Input::test_2(src_path).unwrap_err();
+        assert!(matches!(error, InputError::NotFound { .. }));
+
+        fs::remove_dir_all(&crate_dir).unwrap();
+    }
+
+    #[test]
+    fn test_ints() {
+        let input = Input::from("3, 4,1\n-5\t6\n");
+        assert_eq!(input.ints::<i64>().unwrap(), vec![3, 4, 1, -5, 6]);
+
+        let error = Input::from("1 2\n3 x4").ints::<i64>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.token, "x4");
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = Input::from("#.\n.#\n").grid::<char>().unwrap();
+        assert_eq!(grid.as_slice(), &['#', '.', '.', '#']);
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+
+        let grid = Input::from("ab").grid::<u8>().unwrap();
+        assert_eq!(grid.row(0), b"ab");
+
+        let error = Input::from("ab\na€").grid::<u8>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.token, "€");
+    }
+
+    #[test]
+    fn test_split_blank_lines() {
+        let input = Input::from("a\nb\n\n\nc\r\n\r\nd\n");
+        assert_eq!(input.split_blank_lines(), vec!["a\nb", "c", "d"]);
+        assert!(Input::from("\n\n").split_blank_lines().is_empty());
+    }
+}
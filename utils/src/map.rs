@@ -1,69 +1,221 @@
 use crate::point::MapPoint;
 
+/// A dense map over a rectangle of the `i64` plane, the bounds can be
+/// negative. Writing outside of the bounds grows the map, new cells hold the
+/// fill value.
 #[derive(Debug, Clone, Hash, PartialEq, PartialOrd, Eq, Ord)]
-pub struct Map {
-    pub data: Vec<i64>,
-    pub min_x: i64,
-    pub max_x: i64,
-    pub min_y: i64,
-    pub max_y: i64,
-    pub range_x: i64,
-    pub range_y: i64,
+pub struct Map<T> {
+    data: Vec<T>,
+    min_x: i64,
+    min_y: i64,
+    range_x: i64,
+    range_y: i64,
+    fill: T,
 }
 
-impl Map {
-    pub fn from_vec_vec(grid: Vec<Vec<i64>>) -> Self {
+impl<T: Clone> Map<T> {
+    /// Rows of different lengths are filled up with `T::default()`.
+    pub fn from_vec_vec(grid: Vec<Vec<T>>) -> Self
+    where
+        T: Default,
+    {
         let max_y = grid.len() as i64;
-        let max_x = grid.iter().map(|i| i.len()).max().unwrap() as i64;
+        let max_x = grid.iter().map(|i| i.len()).max().unwrap_or(0) as i64;
 
-
-         Self {
-            data: grid.into_iter().flatten().collect(),
-            min_x: 0,
-            max_x: max_x,
-            min_y: 0,
-            max_y: max_y,
-            range_x: max_x,
-            range_y: max_y,
+        let mut rtn = Self::new(max_x, max_y);
+        for (y, row) in grid.into_iter().enumerate() {
+            for (x, value) in row.into_iter().enumerate() {
+                rtn.set(x as i64, y as i64, value);
+            }
         }
+        rtn
+    }
+
+    /// Covers `0..max_x` and `0..max_y`, filled with `T::default()`.
+    pub fn new(max_x: i64, max_y: i64) -> Self
+    where
+        T: Default,
+    {
+        Self::with_bounds(0, max_x, 0, max_y, T::default())
     }
 
-    pub fn new(max_x: i64, max_y: i64) -> Self {
+    /// Covers `min_x..max_x` and `min_y..max_y`, filled with `fill`.
+    pub fn with_bounds(min_x: i64, max_x: i64, min_y: i64, max_y: i64, fill: T) -> Self {
+        let range_x = (max_x - min_x).max(0);
+        let range_y = (max_y - min_y).max(0);
+
         Self {
-            data: vec![0; (max_x * max_y) as usize],
-            min_x: 0,
-            max_x: max_x,
-            min_y: 0,
-            max_y: max_y,
-            range_x: max_x,
-            range_y: max_y,
+            data: vec![fill.clone(); (range_x * range_y) as usize],
+            min_x,
+            min_y,
+            range_x,
+            range_y,
+            fill,
+        }
+    }
+
+    pub fn min_x(&self) -> i64 {
+        self.min_x
+    }
+
+    /// Exclusive, like the other maximums.
+    pub fn max_x(&self) -> i64 {
+        self.min_x + self.range_x
+    }
+
+    pub fn min_y(&self) -> i64 {
+        self.min_y
+    }
+
+    pub fn max_y(&self) -> i64 {
+        self.min_y + self.range_y
+    }
+
+    fn index(&self, x: i64, y: i64) -> Option<usize> {
+        if self.is_in_map(x, y) {
+            Some(((y - self.min_y) * self.range_x + x - self.min_x) as usize)
+        } else {
+            None
         }
     }
 
-    pub fn get_from_point(&self, point: &MapPoint) -> i64 {
+    pub fn get_from_point(&self, point: &MapPoint) -> Option<&T> {
         self.get(point.x, point.y)
     }
 
-    pub fn get(&self, x: i64, y: i64) -> i64 {
-        let mut rtn = y * self.range_y - self.min_y;
-        rtn += x - self.min_x;
-        return *self.data.get(rtn as usize).unwrap();
+    /// `None` outside of the bounds.
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        self.index(x, y).map(|index| &self.data[index])
     }
 
-    pub fn set(&mut self, x: i64, y: i64, value: i64) {
-        let mut rtn = y * self.range_y - self.min_y;
-        rtn += x - self.min_x;
-        self.data[rtn as usize] = value;
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        self.index(x, y).map(|index| &mut self.data[index])
     }
 
-    pub fn set_at_point(&mut self, point: &MapPoint, value: i64) {
+    /// Like `get_mut`, but grows the map first when `x`, `y` is outside.
+    pub fn get_mut_or_grow(&mut self, x: i64, y: i64) -> &mut T {
+        if !self.is_in_map(x, y) {
+            self.grow(x, y);
+        }
+        let index = self.index(x, y).unwrap();
+        &mut self.data[index]
+    }
+
+    pub fn set(&mut self, x: i64, y: i64, value: T) {
+        *self.get_mut_or_grow(x, y) = value;
+    }
+
+    pub fn set_at_point(&mut self, point: &MapPoint, value: T) {
         self.set(point.x, point.y, value);
     }
 
+    /// Every cell with its point, row by row from `min_y`.
+    pub fn iter(&self) -> impl Iterator<Item = (MapPoint, &T)> {
+        self.data.iter().enumerate().map(|(i, value)| {
+            let i = i as i64;
+            (
+                MapPoint::new(self.min_x + i % self.range_x, self.min_y + i / self.range_x),
+                value,
+            )
+        })
+    }
+
+    /// Extends the bounds to cover `x`, `y` with room to spare on the side it
+    /// grew, so that walking off the map grows it only now and then.
+    fn grow(&mut self, x: i64, y: i64) {
+        let (mut min_x, mut max_x) = (self.min_x, self.max_x());
+        let (mut min_y, mut max_y) = (self.min_y, self.max_y());
+        if x < min_x {
+            min_x = x - self.range_x;
+        } else if x >= max_x {
+            max_x = x + 1 + self.range_x;
+        }
+        if y < min_y {
+            min_y = y - self.range_y;
+        } else if y >= max_y {
+            max_y = y + 1 + self.range_y;
+        }
+
+        let mut grown = Self::with_bounds(min_x, max_x, min_y, max_y, self.fill.clone());
+        let old = std::mem::take(&mut self.data);
+        for (i, value) in old.into_iter().enumerate() {
+            let i = i as i64;
+            let index = grown
+                .index(self.min_x + i % self.range_x, self.min_y + i / self.range_x)
+                .unwrap();
+            grown.data[index] = value;
+        }
+
+        *self = grown;
+    }
+
     pub fn is_point_in_map(&self, point: MapPoint) -> bool {
         self.is_in_map(point.x, point.y)
     }
     pub fn is_in_map(&self, x: i64, y: i64) -> bool {
-        x >= self.min_x && y >= self.min_y && x < self.max_x && y < self.max_y
+        x >= self.min_x && y >= self.min_y && x < self.max_x() && y < self.max_y()
+    }
+}
+
+#[cfg(test)]
+mod map_test {
+    use crate::map::Map;
+    use crate::point::MapPoint;
+
+    #[test]
+    fn test_non_square() {
+        let mut map = Map::new(5, 2);
+        for y in 0..2 {
+            for x in 0..5 {
+                map.set(x, y, x + 10 * y);
+            }
+        }
+
+        assert_eq!(map.get(4, 0), Some(&4));
+        assert_eq!(map.get(0, 1), Some(&10));
+        assert_eq!(map.get(4, 1), Some(&14));
+        assert_eq!(map.get(5, 0), None);
+        assert_eq!(map.get(0, 2), None);
+        assert_eq!(map.get(-1, 0), None);
+    }
+
+    #[test]
+    fn test_negative_bounds() {
+        let mut map = Map::with_bounds(-3, 2, -2, 1, '.');
+        map.set(-3, -2, 'a');
+        map.set(1, 0, 'b');
+        map.set(0, -1, 'c');
+
+        assert_eq!(map.get(-3, -2), Some(&'a'));
+        assert_eq!(map.get(1, 0), Some(&'b'));
+        assert_eq!(map.get(0, -1), Some(&'c'));
+        assert_eq!(map.get(-2, -2), Some(&'.'));
+        assert_eq!(map.iter().filter(|(_, c)| **c != '.').count(), 3);
+        assert_eq!(map.iter().next(), Some((MapPoint::new(-3, -2), &'a')));
+    }
+
+    #[test]
+    fn test_grow() {
+        let mut map = Map::new(2, 2);
+        map.set(1, 1, 5);
+        map.set(-4, 7, 6);
+        *map.get_mut_or_grow(9, -3) += 7;
+
+        assert!(map.min_x() <= -4 && map.max_x() > 9);
+        assert!(map.min_y() <= -3 && map.max_y() > 7);
+        assert_eq!(map.get(1, 1), Some(&5));
+        assert_eq!(map.get(-4, 7), Some(&6));
+        assert_eq!(map.get(9, -3), Some(&7));
+        assert_eq!(map.get(0, 0), Some(&0));
+        assert_eq!(map.iter().map(|(_, value)| value).sum::<i32>(), 18);
+    }
+
+    #[test]
+    fn test_from_vec_vec() {
+        let map = Map::from_vec_vec(vec![vec![1, 2, 3], vec![4]]);
+        assert_eq!((map.max_x(), map.max_y()), (3, 2));
+        assert_eq!(map.get(2, 0), Some(&3));
+        assert_eq!(map.get(0, 1), Some(&4));
+        assert_eq!(map.get(1, 1), Some(&0));
     }
 }
@@ -0,0 +1,290 @@
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Zero when either number is zero.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    a / gcd(a, b) * b
+}
+
+/// `(g, x, y)` with `g = gcd(a, b)` and `a * x + b * y = g`, `g` is never
+/// negative.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `a * b % modulus` without overflowing.
+pub fn mod_mul(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+/// `base ^ exp % modulus` by squaring.
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    if modulus == 1 {
+        return 0;
+    }
+
+    let mut base = base % modulus;
+    let mut rtn = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            rtn = mod_mul(rtn, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exp >>= 1;
+    }
+    rtn
+}
+
+/// The `x` in `0..modulus` with `a * x % modulus == 1`, `None` when `a` and
+/// `modulus` share a factor.
+pub fn mod_inv(a: i64, modulus: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    if g == 1 {
+        Some(x.rem_euclid(modulus))
+    } else {
+        None
+    }
+}
+
+/// Solves `x ≡ r (mod m)` for every `(r, m)` in `congruences`, the moduli
+/// need not be coprime. The result is `(x, lcm of the moduli)` with `x` the
+/// smallest solution that is not negative, `None` when the congruences
+/// contradict each other, a modulus is not positive or the lcm does not fit
+/// into an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut rtn: (i64, i64) = (0, 1);
+    for (residue, modulus) in congruences.iter().copied() {
+        if modulus <= 0 {
+            return None;
+        }
+        let (r1, m1) = (rtn.0 as i128, rtn.1 as i128);
+        let (r2, m2) = (residue.rem_euclid(modulus) as i128, modulus as i128);
+
+        let g = gcd(u64::try_from(m1).ok()?, u64::try_from(m2).ok()?) as i128;
+        if (r2 - r1) % g != 0 {
+            return None;
+        }
+
+        let m2_g = m2 / g;
+        let inverse = mod_inv(
+            i64::try_from(m1 / g % m2_g).ok()?,
+            i64::try_from(m2_g).ok()?,
+        )? as i128;
+        let k = ((r2 - r1) / g).rem_euclid(m2_g) * inverse % m2_g;
+        let modulus = m1 * m2_g;
+        rtn = (
+            i64::try_from((r1 + m1 * k).rem_euclid(modulus)).ok()?,
+            i64::try_from(modulus).ok()?,
+        );
+    }
+    Some(rtn)
+}
+
+/// `rtn[n]` tells whether `n` is prime, for every `n` up to `limit`.
+pub fn sieve(limit: usize) -> Vec<bool> {
+    let mut rtn = vec![true; limit + 1];
+    rtn[0] = false;
+    if limit >= 1 {
+        rtn[1] = false;
+    }
+
+    let mut i = 2;
+    while i * i <= limit {
+        if rtn[i] {
+            for multiple in (i * i..=limit).step_by(i) {
+                rtn[multiple] = false;
+            }
+        }
+        i += 1;
+    }
+    rtn
+}
+
+pub fn primes_up_to(limit: usize) -> Vec<u64> {
+    sieve(limit)
+        .into_iter()
+        .enumerate()
+        .filter(|(_, is_prime)| *is_prime)
+        .map(|(n, _)| n as u64)
+        .collect()
+}
+
+/// Miller–Rabin with a set of bases that is exact for every `u64`.
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    for base in BASES {
+        if n.is_multiple_of(base) {
+            return n == base;
+        }
+    }
+
+    let shift = (n - 1).trailing_zeros();
+    let odd = (n - 1) >> shift;
+    'bases: for base in BASES {
+        let mut x = mod_pow(base, odd, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..shift {
+            x = mod_mul(x, x, n);
+            if x == n - 1 {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+#[cfg(test)]
+mod num_test {
+    use crate::num::{
+        crt, extended_gcd, gcd, is_prime, lcm, mod_inv, mod_pow, primes_up_to, sieve,
+    };
+
+    fn is_prime_by_division(n: u64) -> bool {
+        n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+    }
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        for a in 0..60u64 {
+            for b in 0..60u64 {
+                let expected = (1..=a.max(b))
+                    .filter(|d| a % d == 0 && b % d == 0)
+                    .max()
+                    .unwrap_or(0);
+                assert_eq!(gcd(a, b), expected);
+
+                let expected_lcm = if a == 0 || b == 0 {
+                    0
+                } else {
+                    (1..).map(|k| a * k).find(|m| m % b == 0).unwrap()
+                };
+                assert_eq!(lcm(a, b), expected_lcm);
+
+                let (g, x, y) = extended_gcd(a as i64 - 30, b as i64 - 25);
+                assert_eq!(
+                    g as u64,
+                    gcd(
+                        (a as i64 - 30).unsigned_abs(),
+                        (b as i64 - 25).unsigned_abs()
+                    )
+                );
+                assert_eq!((a as i64 - 30) * x + (b as i64 - 25) * y, g);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mod_pow_and_inv() {
+        for modulus in 1..40u64 {
+            for base in 0..40u64 {
+                let mut expected = 1 % modulus;
+                for exp in 0..20 {
+                    assert_eq!(mod_pow(base, exp, modulus), expected);
+                    expected = expected * base % modulus;
+                }
+
+                let inverse = (0..modulus).find(|x| base * x % modulus == 1 % modulus);
+                assert_eq!(
+                    mod_inv(base as i64, modulus as i64),
+                    inverse.map(|x| x as i64),
+                    "{base} mod {modulus}"
+                );
+            }
+        }
+
+        let big = 18_446_744_073_709_551_557;
+        assert_eq!(mod_pow(3, big - 1, big), 1);
+        assert_eq!(mod_inv(-3, 7), Some(2));
+    }
+
+    #[test]
+    fn test_crt() {
+        let moduli = [2, 3, 4, 6, 9, 10];
+        for &m1 in moduli.iter() {
+            for &m2 in moduli.iter() {
+                for &m3 in moduli.iter() {
+                    let period = lcm(lcm(m1, m2), m3) as i64;
+                    for (r1, r2, r3) in [(0, 1, 2), (1, 1, 1), (1, 2, 5), (3, 0, 7)] {
+                        let congruences = [(r1, m1 as i64), (r2, m2 as i64), (r3, m3 as i64)];
+                        let expected = (0..period)
+                            .find(|x| congruences.iter().all(|(r, m)| (x - r).rem_euclid(*m) == 0));
+                        assert_eq!(
+                            crt(&congruences),
+                            expected.map(|x| (x, period)),
+                            "{congruences:?}"
+                        );
+                    }
+                }
+            }
+        }
+
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+
+        let (x, modulus) = crt(&[(5, 1_000_000_007), (7, 998_244_353)]).unwrap();
+        assert_eq!(modulus, 1_000_000_007 * 998_244_353);
+        assert_eq!((x % 1_000_000_007, x % 998_244_353), (5, 7));
+
+        // The lcm of the moduli does not fit into an i64.
+        assert_eq!(
+            crt(&[(5, 1_000_000_007), (7, 998_244_353), (9, 1_000_000_009)]),
+            None
+        );
+        assert_eq!(crt(&[(0, i64::MAX), (0, i64::MAX - 1)]), None);
+        assert_eq!(crt(&[(3, i64::MAX), (3, i64::MAX)]), Some((3, i64::MAX)));
+
+        assert_eq!(crt(&[(0, 0)]), None);
+        assert_eq!(crt(&[(1, 2), (1, -3)]), None);
+    }
+
+    #[test]
+    fn test_primes() {
+        let primes = sieve(10_000);
+        for n in 0..=10_000u64 {
+            assert_eq!(primes[n as usize], is_prime_by_division(n), "{n}");
+            assert_eq!(is_prime(n), is_prime_by_division(n), "{n}");
+        }
+        assert_eq!(primes_up_to(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(sieve(0), vec![false]);
+        assert_eq!(sieve(1), vec![false, false]);
+
+        for n in 1_000_000_000u64..1_000_001_000 {
+            assert_eq!(is_prime(n), is_prime_by_division(n), "{n}");
+        }
+
+        // Carmichael numbers and strong pseudoprimes to several bases.
+        for n in [561, 41041, 3_215_031_751, 3_825_123_056_546_413_051] {
+            assert!(!is_prime(n), "{n}");
+        }
+        for n in [2_305_843_009_213_693_951, 18_446_744_073_709_551_557] {
+            assert!(is_prime(n), "{n}");
+        }
+    }
+}
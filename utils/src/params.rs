@@ -0,0 +1,156 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::solution::Solution;
+
+/// A `name=value` override of one of a day's parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: String,
+    pub value: String,
+}
+
+impl Param {
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl FromStr for Param {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((name, value)) if !name.trim().is_empty() => {
+                Ok(Param::new(name.trim(), value.trim()))
+            }
+            _ => Err(format!("Expected name=value, found {s:?}")),
+        }
+    }
+}
+
+impl Display for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.name, self.value)
+    }
+}
+
+/// The typed parameters of a day, like the size of the puzzle, whose defaults
+/// are the values of the real puzzle. Declare them with `params!`.
+pub trait Params: Default {
+    /// The names and current values, in declaration order.
+    fn values(&self) -> Vec<(&'static str, String)>;
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String>;
+}
+
+/// Sets `params` on `solution` in order, a later value for the same name wins.
+pub fn apply_params<S: Solution + ?Sized>(
+    solution: &mut S,
+    params: &[Param],
+) -> Result<(), String> {
+    for param in params {
+        solution.set_param(&param.name, &param.value)?;
+    }
+    Ok(())
+}
+
+/// Declares a parameter struct with a default for every field and implements
+/// `Params` for it:
+///
+/// ```
+/// utils::params! {
+///     pub struct Day16Params {
+///         pub dancers: usize = 16,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            $($(#[$field_meta:meta])* pub $field:ident: $ty:ty = $default:expr,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name {
+            $($(#[$field_meta])* pub $field: $ty,)*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self {
+                    $($field: $default,)*
+                }
+            }
+        }
+
+        impl $crate::params::Params for $name {
+            fn values(&self) -> Vec<(&'static str, String)> {
+                vec![$((stringify!($field), self.$field.to_string()),)*]
+            }
+
+            fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+                match name {
+                    $(stringify!($field) => {
+                        self.$field = value.parse().map_err(|error| {
+                            format!("Invalid value {value:?} for parameter {name}: {error}")
+                        })?;
+                    })*
+                    _ => {
+                        let known: &[&str] = &[$(stringify!($field)),*];
+                        return Err(format!(
+                            "Unknown parameter {name}, expected one of: {}",
+                            known.join(", ")
+                        ));
+                    }
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod params_test {
+    use crate::params::{Param, Params};
+
+    crate::params! {
+        pub struct TestParams {
+            pub size: usize = 16,
+            pub name: String = String::from("abc"),
+        }
+    }
+
+    #[test]
+    fn test_param_from_str() {
+        assert_eq!("size=5".parse(), Ok(Param::new("size", "5")));
+        assert_eq!(" size = 5 ".parse(), Ok(Param::new("size", "5")));
+        assert!("size".parse::<Param>().is_err());
+        assert!("=5".parse::<Param>().is_err());
+    }
+
+    #[test]
+    fn test_params() {
+        let mut params = TestParams::default();
+        assert_eq!(params.size, 16);
+
+        params.set("size", "5").unwrap();
+        params.set("name", "xyz").unwrap();
+        assert_eq!(
+            params.values(),
+            vec![("size", "5".to_string()), ("name", "xyz".to_string())]
+        );
+
+        assert!(params.set("size", "five").is_err());
+        assert_eq!(
+            params.set("length", "5"),
+            Err("Unknown parameter length, expected one of: size, name".to_string())
+        );
+    }
+}
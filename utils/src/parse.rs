@@ -0,0 +1,197 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Points at the offending token of an input, lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// `token` should be a slice of `source`, otherwise its first occurrence
+    /// in `source` is used.
+    pub fn new(source: &str, token: &str, message: impl Into<String>) -> Self {
+        let start = source.as_ptr() as usize;
+        let token_start = token.as_ptr() as usize;
+        let offset = if token_start >= start && token_start + token.len() <= start + source.len() {
+            token_start - start
+        } else {
+            source.find(token).unwrap_or(0)
+        };
+
+        Self::at_offset(source, offset, token, message)
+    }
+
+    /// For a token that is missing, points right after the end of `source`.
+    pub fn at_end(source: &str, message: impl Into<String>) -> Self {
+        Self::at_offset(source, source.trim_end().len(), "", message)
+    }
+
+    fn at_offset(source: &str, offset: usize, token: &str, message: impl Into<String>) -> Self {
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            token: token.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Moves the error down by `lines`, for a source that starts further down
+    /// in the input.
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    /// A multi line diagnostic that quotes the line of `source` the error
+    /// points at. `name` is shown as the file name.
+    pub fn render(&self, name: &str, source: &str) -> String {
+        let line_number = self.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        let line = source.lines().nth(self.line - 1).unwrap_or("");
+        let marker = "^".repeat(self.token.chars().count().max(1));
+
+        format!(
+            "error: {self}\n{gutter}--> {name}:{}:{}\n{gutter} |\n{line_number} | {line}\n{gutter} | {}{marker}",
+            self.line,
+            self.column,
+            " ".repeat(self.column - 1)
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if !self.token.is_empty() {
+            write!(f, " {:?}", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token`, a slice of `source`, with `FromStr`.
+pub fn parse_token<T: FromStr>(source: &str, token: &str) -> Result<T, ParseError>
+where
+    T::Err: Display,
+{
+    token
+        .parse()
+        .map_err(|error: T::Err| ParseError::new(source, token, error.to_string()))
+}
+
+/// Parses `token`, a slice of `source`, with a parser whose errors point into
+/// `token`. The error is moved to where `token` is in `source`.
+pub fn parse_within<T: FromStr<Err = ParseError>>(
+    source: &str,
+    token: &str,
+) -> Result<T, ParseError> {
+    token.parse().map_err(|error: ParseError| {
+        let start = ParseError::new(source, token, "");
+        ParseError {
+            line: start.line + error.line - 1,
+            column: if error.line == 1 {
+                start.column + error.column - 1
+            } else {
+                error.column
+            },
+            ..error
+        }
+    })
+}
+
+/// The next token of `tokens`, an error naming `expected` when there is none.
+pub fn next_token<'a>(
+    source: &str,
+    tokens: &mut impl Iterator<Item = &'a str>,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    tokens
+        .next()
+        .ok_or_else(|| ParseError::at_end(source, format!("expected {expected}")))
+}
+
+/// Parses every line of `input`, the errors point at the line in `input`.
+pub fn parse_lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(row, line)| {
+            line.parse()
+                .map_err(|error: ParseError| error.offset_lines(row))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod parse_test {
+    use crate::parse::{next_token, parse_lines, parse_token, parse_within, ParseError};
+
+    #[derive(Debug, PartialEq)]
+    struct Pair(i64, i64);
+
+    impl std::str::FromStr for Pair {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let mut tokens = s.split(' ');
+            let first = parse_token(s, next_token(s, &mut tokens, "a number")?)?;
+            let second = parse_token(s, next_token(s, &mut tokens, "a number")?)?;
+            Ok(Pair(first, second))
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let source = "set a 1\nadd b x";
+        let token = &source[14..15];
+        let error = ParseError::new(source, token, "not a number");
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.to_string(), "line 2, column 7: not a number \"x\"");
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines::<Pair>("1 2\n3 4").unwrap(),
+            vec![Pair(1, 2), Pair(3, 4)]
+        );
+
+        let error = parse_lines::<Pair>("1 2\n3 4\n5 six").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(error.token, "six");
+
+        let error = parse_lines::<Pair>("1 2\n3").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "expected a number");
+    }
+
+    #[test]
+    fn test_parse_within() {
+        let source = "pair 1 x";
+        let error = parse_within::<Pair>(source, &source[5..]).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 8));
+        assert_eq!(error.token, "x");
+    }
+
+    #[test]
+    fn test_render() {
+        let error = parse_lines::<Pair>("1 2\n3 4\n5 six").unwrap_err();
+        assert_eq!(
+            error.render("input.txt", "1 2\n3 4\n5 six"),
+            "error: line 3, column 3: invalid digit found in string \"six\"\n --> input.txt:3:3\n  |\n3 | 5 six\n  |   ^^^"
+        );
+    }
+}
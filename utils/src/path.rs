@@ -0,0 +1,307 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::grid::Grid;
+use crate::grid_point::GridPoint;
+use crate::hash_point_map::HashPointMap;
+use crate::point::MapPoint;
+
+/// A shortest path, `states` runs from the start to the goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// Walks the parent links back from `index` to the start.
+fn reconstruct<S: Clone>(states: &[S], parents: &[usize], mut index: usize) -> Vec<S> {
+    let mut rtn = vec![states[index].clone()];
+    while parents[index] != index {
+        index = parents[index];
+        rtn.push(states[index].clone());
+    }
+    rtn.reverse();
+    rtn
+}
+
+/// The path with the fewest steps from `start` to a state `is_goal` accepts.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut index_of: HashMap<S, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+    let mut parents = vec![0];
+    let mut steps = vec![0];
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&states[index]) {
+            return Some(Path {
+                cost: steps[index],
+                states: reconstruct(&states, &parents, index),
+            });
+        }
+
+        for next in neighbors(&states[index]) {
+            if index_of.contains_key(&next) {
+                continue;
+            }
+
+            let next_index = states.len();
+            index_of.insert(next.clone(), next_index);
+            states.push(next);
+            parents.push(index);
+            steps.push(steps[index] + 1);
+            queue.push_back(next_index);
+        }
+    }
+    None
+}
+
+/// The cheapest path from `start` to a state `is_goal` accepts, the
+/// neighbours come with the cost of the step to them.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    a_star(start, neighbors, |_| C::default(), is_goal)
+}
+
+/// Like `dijkstra`, guided by `heuristic`. The result is only the cheapest
+/// path when the heuristic never overestimates the cost to a goal.
+pub fn a_star<S, C, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut index_of: HashMap<S, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+    let mut parents = vec![0];
+    let mut costs = vec![C::default()];
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        if cost > costs[index] {
+            continue;
+        }
+        if is_goal(&states[index]) {
+            return Some(Path {
+                cost,
+                states: reconstruct(&states, &parents, index),
+            });
+        }
+
+        for (next, step) in neighbors(&states[index]) {
+            let next_cost = cost + step;
+            let next_index = match index_of.get(&next) {
+                Some(next_index) if costs[*next_index] <= next_cost => continue,
+                Some(next_index) => {
+                    costs[*next_index] = next_cost;
+                    parents[*next_index] = index;
+                    *next_index
+                }
+                None => {
+                    let next_index = states.len();
+                    index_of.insert(next.clone(), next_index);
+                    states.push(next);
+                    parents.push(index);
+                    costs.push(next_cost);
+                    next_index
+                }
+            };
+
+            let estimate = next_cost + heuristic(&states[next_index]);
+            heap.push(Reverse((estimate, next_cost, next_index)));
+        }
+    }
+    None
+}
+
+/// Neighbours for `bfs` on a grid: the up to four adjacent cells that
+/// `passable` accepts.
+pub fn grid_steps<'a, T>(
+    grid: &'a Grid<T>,
+    passable: impl Fn(&T) -> bool + 'a,
+) -> impl Fn(&GridPoint) -> Vec<GridPoint> + 'a {
+    move |point| {
+        grid.neighbors4(point)
+            .filter(|(_, cell)| passable(cell))
+            .map(|(next, _)| next)
+            .collect()
+    }
+}
+
+/// Neighbours for `dijkstra` and `a_star` on a grid: `cost` is the price of
+/// entering a cell, `None` for walls.
+pub fn grid_costs<'a, T, C>(
+    grid: &'a Grid<T>,
+    cost: impl Fn(&T) -> Option<C> + 'a,
+) -> impl Fn(&GridPoint) -> Vec<(GridPoint, C)> + 'a {
+    move |point| {
+        grid.neighbors4(point)
+            .filter_map(|(next, cell)| cost(cell).map(|cost| (next, cost)))
+            .collect()
+    }
+}
+
+/// Neighbours for `bfs` on a sparse map, points without a value are walls.
+pub fn map_steps<'a, T>(
+    map: &'a HashPointMap<T>,
+    passable: impl Fn(&T) -> bool + 'a,
+) -> impl Fn(&MapPoint) -> Vec<MapPoint> + 'a {
+    move |point| {
+        point
+            .generate_non_diagonal_neigbors()
+            .into_iter()
+            .filter(|next| map.get(next).is_some_and(&passable))
+            .collect()
+    }
+}
+
+/// Neighbours for `dijkstra` and `a_star` on a sparse map, like `grid_costs`.
+pub fn map_costs<'a, T, C>(
+    map: &'a HashPointMap<T>,
+    cost: impl Fn(&T) -> Option<C> + 'a,
+) -> impl Fn(&MapPoint) -> Vec<(MapPoint, C)> + 'a {
+    move |point| {
+        point
+            .generate_non_diagonal_neigbors()
+            .into_iter()
+            .filter_map(|next| map.get(&next).and_then(&cost).map(|cost| (next, cost)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod path_test {
+    use crate::grid::Grid;
+    use crate::grid_point::GridPoint;
+    use crate::hash_point_map::HashPointMap;
+    use crate::path::{a_star, bfs, dijkstra, grid_costs, grid_steps, map_costs, map_steps};
+    use crate::point::MapPoint;
+    use crate::vector::Vec2;
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#...
+.####.#.
+......#E";
+
+    fn point(x: usize, y: usize) -> GridPoint {
+        GridPoint { x, y }
+    }
+
+    #[test]
+    fn test_bfs_on_grid() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let start = grid.find(&'S').unwrap();
+        let end = grid.find(&'E').unwrap();
+
+        let path = bfs(start, grid_steps(&grid, |c| *c != '#'), |p| *p == end).unwrap();
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.states.len(), 16);
+        assert_eq!(path.states.first(), Some(&start));
+        assert_eq!(path.states.last(), Some(&end));
+        assert!(path
+            .states
+            .windows(2)
+            .all(|w| Vec2::from(w[0]).manhattan(&Vec2::from(w[1])) == 1));
+
+        assert_eq!(
+            bfs(start, grid_steps(&grid, |c| *c == '.'), |p| *p == end),
+            None
+        );
+        let here = bfs(start, grid_steps(&grid, |_| true), |p| *p == start).unwrap();
+        assert_eq!((here.cost, here.states), (0, vec![start]));
+    }
+
+    #[test]
+    fn test_weighted_grid() {
+        let grid = Grid::parse("1163751\n1381373\n2136511\n3694931\n7463417", |c| {
+            c.to_digit(10).unwrap() as u64
+        });
+        let end = point(6, 4);
+        let costs = grid_costs(&grid, |c| Some(*c));
+
+        let cheapest = dijkstra(point(0, 0), &costs, |p| *p == end).unwrap();
+        let guided = a_star(
+            point(0, 0),
+            &costs,
+            |p| Vec2::from(*p).manhattan(&Vec2::from(end)) as u64,
+            |p| *p == end,
+        )
+        .unwrap();
+
+        assert_eq!(cheapest.cost, brute_force(&grid, end));
+        assert_eq!(guided.cost, cheapest.cost);
+        let summed: u64 = cheapest.states[1..].iter().map(|p| grid[(p.x, p.y)]).sum();
+        assert_eq!(summed, cheapest.cost);
+    }
+
+    /// Relaxes every edge until nothing changes, Bellman–Ford style.
+    fn brute_force(grid: &Grid<u64>, end: GridPoint) -> u64 {
+        let mut best = grid.map(|_| u64::MAX);
+        best[(0, 0)] = 0;
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (p, _) in grid.iter_with_coords() {
+                for (next, cost) in grid.neighbors4(&p) {
+                    if best[(p.x, p.y)] != u64::MAX
+                        && best[(p.x, p.y)] + cost < best[(next.x, next.y)]
+                    {
+                        best[(next.x, next.y)] = best[(p.x, p.y)] + cost;
+                        changed = true;
+                    }
+                }
+            }
+        }
+        best[(end.x, end.y)]
+    }
+
+    #[test]
+    fn test_hash_point_map() {
+        let mut map = HashPointMap::default();
+        for x in -3..=3 {
+            map.push(MapPoint::new(x, 0), 1);
+            map.push(MapPoint::new(x, 2), 1);
+        }
+        map.push(MapPoint::new(-3, 1), 5);
+        map.push(MapPoint::new(3, 1), 1);
+
+        let start = MapPoint::new(0, 0);
+        let end = MapPoint::new(0, 2);
+        let steps = bfs(start, map_steps(&map, |_| true), |p| *p == end).unwrap();
+        assert_eq!(steps.cost, 8);
+
+        let cheapest = dijkstra(start, map_costs(&map, |c| Some(*c)), |p| *p == end).unwrap();
+        assert_eq!(cheapest.cost, 8);
+        assert!(cheapest.states.contains(&MapPoint::new(3, 1)));
+
+        let costly = dijkstra(start, map_costs(&map, |c| (*c > 1).then_some(*c)), |p| {
+            *p == end
+        });
+        assert_eq!(costly, None);
+    }
+}
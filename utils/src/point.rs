@@ -1,4 +1,6 @@
-use crate::{map_direction::MapDirection, utils::manhatten_distance};
+use crate::direction::Direction;
+use crate::utils::manhatten_distance;
+use crate::vector::Vec2;
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
 pub struct MapPoint {
@@ -14,7 +16,7 @@ impl MapPoint {
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct MapWalker {
-    pub direction: MapDirection,
+    pub direction: Direction,
     pub position: MapPoint,
 }
 
@@ -32,39 +34,19 @@ impl<T> MapInfoWalker<T> {
 
 impl MapWalker {
     pub fn r#move(&mut self) {
-        match self.direction {
-            MapDirection::Up => self.position.move_up(),
-            MapDirection::Right => self.position.move_right(),
-            MapDirection::Down => self.position.move_down(),
-            MapDirection::Left => self.position.move_left(),
-        };
+        self.position = self.position.next_from_direction(&self.direction);
     }
 
     pub fn turn_left(&mut self) {
-        self.direction = match self.direction {
-            MapDirection::Up => MapDirection::Left,
-            MapDirection::Right => MapDirection::Up,
-            MapDirection::Down => MapDirection::Right,
-            MapDirection::Left => MapDirection::Down,
-        }
+        self.direction = self.direction.turn_left();
     }
 
     pub fn turn_right(&mut self) {
-        self.direction = match self.direction {
-            MapDirection::Up => MapDirection::Right,
-            MapDirection::Right => MapDirection::Down,
-            MapDirection::Down => MapDirection::Left,
-            MapDirection::Left => MapDirection::Up,
-        }
+        self.direction = self.direction.turn_right();
     }
 
     pub fn turn_around(&mut self) {
-        self.direction = match self.direction {
-            MapDirection::Up => MapDirection::Down,
-            MapDirection::Right => MapDirection::Left,
-            MapDirection::Down => MapDirection::Up,
-            MapDirection::Left => MapDirection::Right,
-        }
+        self.direction = self.direction.opposite();
     }
 }
 
@@ -126,13 +108,8 @@ impl MapPoint {
         ]
     }
 
-    pub fn next_from_direction(&self, direction: &MapDirection) -> Self {
-        match direction {
-            MapDirection::Up => self.next_up(),
-            MapDirection::Right => self.next_right(),
-            MapDirection::Down => self.next_down(),
-            MapDirection::Left => self.next_left(),
-        }
+    pub fn next_from_direction(&self, direction: &Direction) -> Self {
+        (Vec2::from(*self) + direction.map_delta()).into()
     }
 
     pub fn move_right(&mut self) {
@@ -164,32 +141,4 @@ impl MapPoint {
     pub fn manhatten_distance(&self, point: Self) -> i64 {
         manhatten_distance(self.x, self.y, point.x, point.y)
     }
-
-    fn next_up(&self) -> MapPoint {
-        Self {
-            x: self.x,
-            y: self.y + 1,
-        }
-    }
-
-    fn next_left(&self) -> MapPoint {
-        Self {
-            x: self.x - 1,
-            y: self.y,
-        }
-    }
-
-    fn next_down(&self) -> MapPoint {
-        Self {
-            x: self.x,
-            y: self.y - 1,
-        }
-    }
-
-    fn next_right(&self) -> MapPoint {
-        Self {
-            x: self.x + 1,
-            y: self.y + 1,
-        }
-    }
 }
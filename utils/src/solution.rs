@@ -0,0 +1,157 @@
+use std::env;
+use std::fmt::Display;
+use std::process;
+
+use crate::input::Input;
+use crate::parse::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    Empty,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+            Answer::Empty => write!(f, "-"),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn get_parts() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("There is no part {value}")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A day of the calendar. The input is parsed once and both parts are solved
+/// from the parsed state.
+pub trait Solution {
+    fn parse(input: &str) -> Result<Self, ParseError>
+    where
+        Self: Sized;
+
+    fn part1(&self) -> Answer;
+
+    fn part2(&self) -> Answer;
+
+    /// Overrides one of the day's parameters, see `utils::params`. Days
+    /// without parameters reject every name.
+    fn set_param(&mut self, name: &str, _value: &str) -> Result<(), String> {
+        Err(format!("Unknown parameter {name}, this day has no parameters"))
+    }
+
+    /// The names and current values of the day's parameters.
+    fn params(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    fn solve(&self, part: Part) -> Answer {
+        match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2(),
+        }
+    }
+}
+
+/// Solves both parts of a day and prints the answers. The input is the path
+/// given as first argument, or the `input/input.txt` next to `src_path`.
+pub fn run_solution<S: Solution>(src_path: &str) {
+    let input = match env::args_os().nth(1) {
+        Some(path) => Input::from_path(path),
+        None => Input::puzzle(src_path),
+    };
+    let input = input.unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(1);
+    });
+    if let Some(path) = input.path() {
+        println!("{:?}", path);
+    }
+
+    let solution = S::parse(input.text()).unwrap_or_else(|error| {
+        let name = input.path().map(|path| path.display().to_string());
+        eprintln!("{}", error.render(&name.unwrap_or_default(), input.text()));
+        process::exit(1);
+    });
+
+    println!("Result of part 1 is {}", solution.part1());
+    println!("Result of part 2 is {}", solution.part2());
+}
@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Disjoint sets over the ids `0..len`, with path compression and union by
+/// rank. Every id starts in a set of its own.
+#[derive(Debug, Clone, Default)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    set_count: usize,
+}
+
+impl UnionFind {
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            size: vec![1; len],
+            set_count: len,
+        }
+    }
+
+    /// Adds a new id in a set of its own and returns it.
+    pub fn add(&mut self) -> usize {
+        let id = self.parent.len();
+        self.parent.push(id);
+        self.rank.push(0);
+        self.size.push(1);
+        self.set_count += 1;
+        id
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The representative of the set of `id`.
+    pub fn find(&mut self, mut id: usize) -> usize {
+        while self.parent[id] != id {
+            let grandparent = self.parent[self.parent[id]];
+            self.parent[id] = grandparent;
+            id = grandparent;
+        }
+        id
+    }
+
+    /// Merges the sets of `a` and `b`, `false` when they already were one.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        if self.rank[a] < self.rank[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        if self.rank[a] == self.rank[b] {
+            self.rank[a] += 1;
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.set_count -= 1;
+        true
+    }
+
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    pub fn set_size(&mut self, id: usize) -> usize {
+        let root = self.find(id);
+        self.size[root]
+    }
+
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+
+    /// Every set with its ids in ascending order, ordered by smallest id.
+    pub fn sets(&mut self) -> Vec<Vec<usize>> {
+        let mut index_of_root: HashMap<usize, usize> = HashMap::with_capacity(self.set_count);
+        let mut rtn: Vec<Vec<usize>> = Vec::with_capacity(self.set_count);
+        for id in 0..self.len() {
+            let root = self.find(id);
+            let index = *index_of_root.entry(root).or_insert_with(|| {
+                rtn.push(Vec::new());
+                rtn.len() - 1
+            });
+            rtn[index].push(id);
+        }
+        rtn
+    }
+}
+
+/// A `UnionFind` over labels instead of ids. Labels are added the first time
+/// they are used.
+#[derive(Debug, Clone)]
+pub struct LabelUnionFind<T> {
+    ids: HashMap<T, usize>,
+    labels: Vec<T>,
+    sets: UnionFind,
+}
+
+impl<T> Default for LabelUnionFind<T> {
+    fn default() -> Self {
+        Self {
+            ids: HashMap::new(),
+            labels: Vec::new(),
+            sets: UnionFind::default(),
+        }
+    }
+}
+
+impl<T: Clone + Eq + Hash> LabelUnionFind<T> {
+    /// The id of `label`, adding it in a set of its own when it is new.
+    pub fn insert(&mut self, label: T) -> usize {
+        if let Some(id) = self.ids.get(&label) {
+            return *id;
+        }
+
+        let id = self.sets.add();
+        self.ids.insert(label.clone(), id);
+        self.labels.push(label);
+        id
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    pub fn union(&mut self, a: T, b: T) -> bool {
+        let a = self.insert(a);
+        let b = self.insert(b);
+        self.sets.union(a, b)
+    }
+
+    /// The representative label of the set of `label`, `None` for unknown
+    /// labels.
+    pub fn find(&mut self, label: &T) -> Option<&T> {
+        let id = *self.ids.get(label)?;
+        let root = self.sets.find(id);
+        Some(&self.labels[root])
+    }
+
+    /// Unknown labels are only in the same set as themselves.
+    pub fn same_set(&mut self, a: &T, b: &T) -> bool {
+        match (self.ids.get(a), self.ids.get(b)) {
+            (Some(a), Some(b)) => {
+                let (a, b) = (*a, *b);
+                self.sets.same_set(a, b)
+            }
+            _ => a == b,
+        }
+    }
+
+    /// Unknown labels are in a set of their own.
+    pub fn set_size(&mut self, label: &T) -> usize {
+        match self.ids.get(label) {
+            Some(id) => {
+                let id = *id;
+                self.sets.set_size(id)
+            }
+            None => 1,
+        }
+    }
+
+    pub fn set_count(&self) -> usize {
+        self.sets.set_count()
+    }
+
+    /// Every set, ordered like the labels were added.
+    pub fn sets(&mut self) -> Vec<Vec<&T>> {
+        self.sets
+            .sets()
+            .into_iter()
+            .map(|set| set.into_iter().map(|id| &self.labels[id]).collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod union_find_test {
+    use crate::union_find::{LabelUnionFind, UnionFind};
+
+    /// Checks the sets against a plain flood fill over the same unions.
+    fn check_against_brute_force(len: usize, unions: &[(usize, usize)]) {
+        let mut sets = UnionFind::new(len);
+        let mut group: Vec<usize> = (0..len).collect();
+        for (a, b) in unions.iter().copied() {
+            let merged = group[a] != group[b];
+            assert_eq!(sets.union(a, b), merged);
+
+            let (from, to) = (group[b], group[a]);
+            group
+                .iter_mut()
+                .filter(|g| **g == from)
+                .for_each(|g| *g = to);
+        }
+
+        for a in 0..len {
+            assert_eq!(
+                sets.set_size(a),
+                group.iter().filter(|g| **g == group[a]).count()
+            );
+            for b in 0..len {
+                assert_eq!(sets.same_set(a, b), group[a] == group[b]);
+            }
+        }
+
+        let mut distinct = group.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        assert_eq!(sets.set_count(), distinct.len());
+        assert_eq!(sets.sets().len(), distinct.len());
+    }
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(4, 5));
+        assert!(sets.union(1, 5));
+        assert!(!sets.union(0, 4));
+
+        assert!(sets.same_set(0, 5));
+        assert!(!sets.same_set(2, 3));
+        assert_eq!(sets.set_size(4), 4);
+        assert_eq!(sets.set_count(), 3);
+        assert_eq!(sets.sets(), vec![vec![0, 1, 4, 5], vec![2], vec![3]]);
+
+        let id = sets.add();
+        assert_eq!((id, sets.len(), sets.set_count()), (6, 7, 4));
+    }
+
+    #[test]
+    fn test_against_brute_force() {
+        let mut seed: u64 = 7;
+        let mut next = |n: usize| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) as usize % n
+        };
+
+        for len in [1, 2, 10, 40] {
+            let unions: Vec<(usize, usize)> = (0..len).map(|_| (next(len), next(len))).collect();
+            check_against_brute_force(len, &unions);
+        }
+    }
+
+    #[test]
+    fn test_long_chain() {
+        let len = 1_000_000;
+        let mut sets = UnionFind::new(len);
+        for i in 1..len {
+            sets.union(i - 1, i);
+        }
+        assert_eq!(sets.set_count(), 1);
+        assert_eq!(sets.set_size(0), len);
+        assert!(sets.same_set(0, len - 1));
+    }
+
+    #[test]
+    fn test_labels() {
+        let mut sets = LabelUnionFind::default();
+        sets.union("a", "b");
+        sets.union("c", "d");
+        sets.union("b", "d");
+        sets.insert("e");
+
+        assert!(sets.same_set(&"a", &"c"));
+        assert!(!sets.same_set(&"a", &"e"));
+        assert!(!sets.same_set(&"a", &"x"));
+        assert!(sets.same_set(&"x", &"x"));
+        assert_eq!(sets.set_size(&"d"), 4);
+        assert_eq!(sets.set_size(&"x"), 1);
+        assert_eq!(sets.find(&"x"), None);
+        let root = sets.find(&"a").copied();
+        assert_eq!(sets.find(&"d").copied(), root);
+        assert_eq!((sets.len(), sets.set_count()), (5, 2));
+        assert_eq!(sets.sets(), vec![vec![&"a", &"b", &"c", &"d"], vec![&"e"]]);
+    }
+}
@@ -0,0 +1,262 @@
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+use crate::grid_point::GridPoint;
+use crate::point::MapPoint;
+
+/// A point or offset with `N` coordinates. It has no opinion on which way an
+/// axis grows, that is up to the type it is converted to: `MapPoint` keeps y
+/// growing upward, `GridPoint` keeps y growing downward.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point<const N: usize, T = i64>(pub [T; N]);
+
+pub type Vec2<T = i64> = Point<2, T>;
+pub type Vec3<T = i64> = Point<3, T>;
+
+impl<const N: usize, T> Point<N, T> {
+    pub fn new(coords: [T; N]) -> Self {
+        Self(coords)
+    }
+}
+
+impl<const N: usize, T: Default> Default for Point<N, T> {
+    fn default() -> Self {
+        Self(std::array::from_fn(|_| T::default()))
+    }
+}
+
+fn abs_diff<T: Copy + PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<const N: usize, T> Point<N, T>
+where
+    T: Copy + Default + PartialOrd + Add<Output = T> + Sub<Output = T>,
+{
+    /// The sum of the distances along each axis.
+    pub fn manhattan(&self, other: &Self) -> T {
+        (0..N).fold(T::default(), |sum, i| sum + abs_diff(self.0[i], other.0[i]))
+    }
+
+    /// The largest distance along any axis, the number of king moves.
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (0..N)
+            .map(|i| abs_diff(self.0[i], other.0[i]))
+            .fold(T::default(), |max, d| if d > max { d } else { max })
+    }
+}
+
+impl<T: Copy> Point<2, T> {
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+}
+
+impl<T: Copy> Point<3, T> {
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> T {
+        self.0[2]
+    }
+}
+
+impl<T> From<(T, T)> for Point<2, T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self([x, y])
+    }
+}
+
+impl<T> From<(T, T, T)> for Point<3, T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self([x, y, z])
+    }
+}
+
+impl<const N: usize, T> Index<usize> for Point<N, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize, T> IndexMut<usize> for Point<N, T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize, T: Copy + Add<Output = T>> Add for Point<N, T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i] + other.0[i]))
+    }
+}
+
+impl<const N: usize, T: Copy + Add<Output = T>> AddAssign for Point<N, T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: usize, T: Copy + Sub<Output = T>> Sub for Point<N, T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i] - other.0[i]))
+    }
+}
+
+impl<const N: usize, T: Copy + Sub<Output = T>> SubAssign for Point<N, T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const N: usize, T: Copy + Mul<Output = T>> Mul<T> for Point<N, T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self(self.0.map(|c| c * factor))
+    }
+}
+
+impl<const N: usize, T: Neg<Output = T>> Neg for Point<N, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.map(|c| -c))
+    }
+}
+
+impl From<MapPoint> for Vec2 {
+    fn from(point: MapPoint) -> Self {
+        Self([point.x, point.y])
+    }
+}
+
+impl From<Vec2> for MapPoint {
+    fn from(point: Vec2) -> Self {
+        MapPoint::new(point.x(), point.y())
+    }
+}
+
+impl From<GridPoint> for Vec2 {
+    fn from(point: GridPoint) -> Self {
+        Self([point.x as i64, point.y as i64])
+    }
+}
+
+impl TryFrom<Vec2> for GridPoint {
+    type Error = String;
+
+    fn try_from(point: Vec2) -> Result<Self, Self::Error> {
+        match (usize::try_from(point.x()), usize::try_from(point.y())) {
+            (Ok(x), Ok(y)) => Ok(GridPoint { x, y }),
+            _ => Err(format!(
+                "({}, {}) is not a grid point, the coordinates must not be negative",
+                point.x(),
+                point.y()
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod vector_test {
+    use crate::direction::Direction;
+    use crate::grid_point::GridPoint;
+    use crate::point::MapPoint;
+    use crate::vector::{Point, Vec2, Vec3};
+
+    #[test]
+    fn test_operators() {
+        let a = Vec3::new([1, -2, 3]);
+        let b = Vec3::new([4, 5, -6]);
+
+        assert_eq!(a + b, Vec3::new([5, 3, -3]));
+        assert_eq!(a - b, Vec3::new([-3, -7, 9]));
+        assert_eq!(a * 3, Vec3::new([3, -6, 9]));
+        assert_eq!(-a, Vec3::new([-1, 2, -3]));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+        assert_eq!((c[0], c.y(), c.z()), (4, 5, -6));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Vec2::from((1, -2));
+        let b = Vec2::from((-3, 5));
+        assert_eq!(a.manhattan(&b), 11);
+        assert_eq!(a.chebyshev(&b), 7);
+        assert_eq!(b.manhattan(&a), 11);
+
+        let origin = Vec3::default();
+        assert_eq!(Vec3::new([-1, 2, -3]).manhattan(&origin), 6);
+        assert_eq!(Vec3::new([-1, 2, -3]).chebyshev(&origin), 3);
+
+        let unsigned: Point<2, usize> = Point::new([2, 7]);
+        assert_eq!(unsigned.manhattan(&Point::new([5, 1])), 9);
+        assert_eq!(unsigned.chebyshev(&Point::new([5, 1])), 6);
+    }
+
+    #[test]
+    fn test_map_point_directions() {
+        let start = MapPoint::new(3, 3);
+        let steps = [
+            (Direction::Up, (0, 1)),
+            (Direction::Right, (1, 0)),
+            (Direction::Down, (0, -1)),
+            (Direction::Left, (-1, 0)),
+        ];
+
+        for (direction, delta) in steps {
+            let expected = MapPoint::from(Vec2::from(start) + Vec2::from(delta));
+            assert_eq!(start.next_from_direction(&direction), expected);
+        }
+    }
+
+    #[test]
+    fn test_grid_point_directions() {
+        let start = GridPoint { x: 3, y: 3 };
+        let steps = [
+            (Direction::Up, (0, -1)),
+            (Direction::Right, (1, 0)),
+            (Direction::Down, (0, 1)),
+            (Direction::Left, (-1, 0)),
+        ];
+
+        for (direction, delta) in steps {
+            let expected = GridPoint::try_from(Vec2::from(start) + Vec2::from(delta));
+            assert_eq!(Ok(start.next_by_direction(&direction)), expected);
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        let map_point = MapPoint::new(-4, 7);
+        assert_eq!(MapPoint::from(Vec2::from(map_point)), map_point);
+
+        let grid_point = GridPoint { x: 2, y: 9 };
+        assert_eq!(GridPoint::try_from(Vec2::from(grid_point)), Ok(grid_point));
+        assert!(GridPoint::try_from(Vec2::from((0, -1))).is_err());
+        assert!(GridPoint::try_from(Vec2::from((-1, 0))).is_err());
+    }
+}